@@ -0,0 +1,325 @@
+/*!
+Implementation of the `Arn` derive macro; the `#[arn(...)]` attribute provides the service and a
+resource template, the fields `partition`, `region`, and `account` (or `account_id`) map to the
+corresponding ResourceName components.
+*/
+
+use aws_arn::{Identifier, IdentifierLike};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitStr};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+enum Segment {
+    Text(String),
+    Field(Ident),
+}
+
+#[derive(Debug, Default)]
+struct Mapping {
+    partition: Option<Ident>,
+    region: Option<Ident>,
+    account: Option<Ident>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const ATTRIBUTE: &str = "arn";
+
+const FIELD_PARTITION: &str = "partition";
+const FIELD_REGION: &str = "region";
+const FIELD_ACCOUNT: &[&str] = &["account", "account_id"];
+
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "Arn can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "Arn can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let (service, template) = attribute(input)?;
+    let segments = segments(&template)?;
+
+    let mut mapping = Mapping::default();
+    for field in &fields.named {
+        let ident = field.ident.clone().unwrap();
+        let name = ident.to_string();
+        if name == FIELD_PARTITION {
+            mapping.partition = Some(ident);
+        } else if name == FIELD_REGION {
+            mapping.region = Some(ident);
+        } else if FIELD_ACCOUNT.contains(&name.as_str()) {
+            mapping.account = Some(ident);
+        } else if !segments
+            .iter()
+            .any(|s| matches!(s, Segment::Field(f) if f == &ident))
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!("field `{}` does not appear in the resource template", name),
+            ));
+        }
+    }
+    for segment in &segments {
+        if let Segment::Field(ident) = segment {
+            if !fields.named.iter().any(|f| f.ident.as_ref() == Some(ident)) {
+                return Err(syn::Error::new_spanned(
+                    &template,
+                    format!("resource template refers to unknown field `{}`", ident),
+                ));
+            }
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let to_partition = match &mapping.partition {
+        Some(f) => quote! {
+            ::core::option::Option::Some(
+                <::aws_arn::Identifier as ::aws_arn::IdentifierLike>::new_unchecked(
+                    &v.#f.to_string()
+                )
+            )
+        },
+        None => quote! { ::core::option::Option::None },
+    };
+    let to_region = match &mapping.region {
+        Some(f) => quote! {
+            ::core::option::Option::Some(
+                <::aws_arn::Identifier as ::aws_arn::IdentifierLike>::new_unchecked(
+                    &v.#f.to_string()
+                )
+            )
+        },
+        None => quote! { ::core::option::Option::None },
+    };
+    let to_account = match &mapping.account {
+        Some(f) => quote! {
+            ::core::option::Option::Some(
+                <::aws_arn::AccountIdentifier as ::aws_arn::IdentifierLike>::new_unchecked(
+                    &v.#f.to_string()
+                )
+            )
+        },
+        None => quote! { ::core::option::Option::None },
+    };
+    let to_resource = segments.iter().map(|segment| match segment {
+        Segment::Text(text) => quote! { resource.push_str(#text); },
+        Segment::Field(f) => quote! { resource.push_str(&v.#f.to_string()); },
+    });
+
+    let from_partition = mapping.partition.as_ref().map(|f| {
+        quote! {
+            let #f = match &__arn.partition {
+                ::core::option::Option::Some(p) => p.to_string(),
+                ::core::option::Option::None => ::aws_arn::known::Partition::default().to_string(),
+            };
+            let #f = #f.parse().map_err(|_| ::aws_arn::Error::InvalidPartition)?;
+        }
+    });
+    let from_region = mapping.region.as_ref().map(|f| {
+        quote! {
+            let #f = __arn
+                .region
+                .as_ref()
+                .filter(|r| !r.is_empty())
+                .ok_or(::aws_arn::Error::MissingRegion)?
+                .parse()
+                .map_err(|_| ::aws_arn::Error::InvalidRegion)?;
+        }
+    });
+    let from_account = mapping.account.as_ref().map(|f| {
+        quote! {
+            let #f = __arn
+                .account_id
+                .as_ref()
+                .filter(|a| !a.is_empty())
+                .ok_or(::aws_arn::Error::MissingAccountId)?;
+            let #f = #f
+                .parse()
+                .map_err(|_| ::aws_arn::Error::InvalidAccountId(#f.to_string()))?;
+        }
+    });
+    let from_resource = segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| match segment {
+            Segment::Text(text) => quote! {
+                let __rest = __rest.strip_prefix(#text).ok_or_else(__invalid)?;
+            },
+            Segment::Field(f) => match segments.get(i + 1) {
+                Some(Segment::Text(next)) => quote! {
+                    let (#f, __rest) = __rest.split_at(__rest.find(#next).ok_or_else(__invalid)?);
+                    let #f = #f.parse().map_err(|_| __invalid())?;
+                },
+                _ => quote! {
+                    let (#f, __rest) = (__rest, "");
+                    let #f = #f.parse().map_err(|_| __invalid())?;
+                },
+            },
+        });
+    let field_names = fields.named.iter().map(|f| f.ident.as_ref().unwrap());
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<&#name #type_generics> for ::aws_arn::ResourceName
+        #where_clause
+        {
+            fn from(v: &#name #type_generics) -> Self {
+                let mut resource = ::std::string::String::new();
+                #(#to_resource)*
+                ::aws_arn::ResourceName {
+                    partition: #to_partition,
+                    service: <::aws_arn::Identifier as ::aws_arn::IdentifierLike>::new_unchecked(
+                        #service
+                    ),
+                    region: #to_region,
+                    account_id: #to_account,
+                    resource: <::aws_arn::ResourceIdentifier as ::aws_arn::IdentifierLike>::new_unchecked(
+                        &resource
+                    ),
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #type_generics> for ::aws_arn::ResourceName
+        #where_clause
+        {
+            fn from(v: #name #type_generics) -> Self {
+                ::aws_arn::ResourceName::from(&v)
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<&::aws_arn::ResourceName> for #name #type_generics
+        #where_clause
+        {
+            type Error = ::aws_arn::Error;
+
+            fn try_from(__arn: &::aws_arn::ResourceName) -> ::core::result::Result<Self, Self::Error> {
+                if &*__arn.service != #service {
+                    return ::core::result::Result::Err(::aws_arn::Error::ResourceMismatch {
+                        expected: ::std::string::ToString::to_string(#service),
+                        found: ::std::string::ToString::to_string(&__arn.service),
+                    });
+                }
+                #from_partition
+                #from_region
+                #from_account
+                let __invalid = || ::aws_arn::Error::InvalidResource(__arn.resource.to_string());
+                let __rest: &str = &__arn.resource;
+                #(#from_resource)*
+                if !__rest.is_empty() {
+                    return ::core::result::Result::Err(__invalid());
+                }
+                ::core::result::Result::Ok(Self {
+                    #(#field_names),*
+                })
+            }
+        }
+
+        impl #impl_generics ::std::str::FromStr for #name #type_generics
+        #where_clause
+        {
+            type Err = ::aws_arn::Error;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                let arn: ::aws_arn::ResourceName = s.parse()?;
+                <Self as ::core::convert::TryFrom<&::aws_arn::ResourceName>>::try_from(&arn)
+            }
+        }
+
+        impl #impl_generics ::std::fmt::Display for #name #type_generics
+        #where_clause
+        {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}", ::aws_arn::ResourceName::from(self))
+            }
+        }
+    })
+}
+
+fn attribute(input: &DeriveInput) -> syn::Result<(LitStr, LitStr)> {
+    let mut service: Option<LitStr> = None;
+    let mut resource: Option<LitStr> = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident(ATTRIBUTE)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("service") {
+                let value: LitStr = meta.value()?.parse()?;
+                if !Identifier::is_valid(&value.value()) {
+                    return Err(meta.error("service must be a valid identifier"));
+                }
+                service = Some(value);
+                Ok(())
+            } else if meta.path.is_ident("resource") {
+                resource = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `service` or `resource`"))
+            }
+        })?;
+    }
+    match (service, resource) {
+        (Some(service), Some(resource)) => Ok((service, resource)),
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "expected an `#[arn(service = \"...\", resource = \"...\")]` attribute",
+        )),
+    }
+}
+
+fn segments(template: &LitStr) -> syn::Result<Vec<Segment>> {
+    let value = template.value();
+    let mut segments = Vec::new();
+    let mut rest = value.as_str();
+    while let Some(start) = rest.find('{') {
+        if start > 0 {
+            segments.push(Segment::Text(rest[..start].to_string()));
+        }
+        let end = rest[start..].find('}').ok_or_else(|| {
+            syn::Error::new_spanned(template, "unterminated `{` in resource template")
+        })?;
+        let name = &rest[start + 1..start + end];
+        let ident = syn::parse_str::<Ident>(name).map_err(|_| {
+            syn::Error::new_spanned(
+                template,
+                format!("`{}` in resource template is not a field name", name),
+            )
+        })?;
+        if matches!(segments.last(), Some(Segment::Field(_))) {
+            return Err(syn::Error::new_spanned(
+                template,
+                "fields in the resource template must be separated by text",
+            ));
+        }
+        segments.push(Segment::Field(ident));
+        rest = &rest[start + end + 1..];
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest.to_string()));
+    }
+    if segments.is_empty() {
+        return Err(syn::Error::new_spanned(
+            template,
+            "resource template must not be empty",
+        ));
+    }
+    Ok(segments)
+}
@@ -0,0 +1,153 @@
+/*!
+Provides procedural macros for the [aws-arn](https://docs.rs/aws-arn) crate.
+
+# ResourceName Literals
+
+The `arn!` macro takes a string literal and checks it, at compile time, with the same rules as
+`ResourceName::from_str`. The macro expands to an infallible constructor for the corresponding
+`ResourceName` value, and any error is reported as a compile error naming the faulty component.
+
+```rust
+use aws_arn::ResourceName;
+use aws_arn_macros::arn;
+
+let arn: ResourceName = arn!("arn:aws:iam::aws:policy/ReadOnlyAccess");
+assert_eq!(arn.to_string(), "arn:aws:iam::aws:policy/ReadOnlyAccess");
+```
+
+Placeholders of the form `${name}`, where `name` is a Rust identifier, are bound to the local
+variable of the same name. A placeholder for the partition, service, or region must make up the
+whole component and the variable may be any value that implements `Into<Identifier>`, such as the
+`known` enums; a placeholder for the account must make up the whole component and the variable
+must be an `AccountIdentifier`. Placeholders in the resource component may be mixed with other
+text and the variable must implement `IdentifierLike`.
+
+```rust
+use aws_arn::known::Region;
+use aws_arn::{AccountIdentifier, Identifier, ResourceName};
+use aws_arn_macros::arn;
+use std::str::FromStr;
+
+let region = Region::UsEast2;
+let account = AccountIdentifier::from_str("123456789012").unwrap();
+let name = Identifier::from_str("my-function").unwrap();
+
+let arn: ResourceName = arn!("arn:aws:lambda:${region}:${account}:function:${name}");
+assert_eq!(
+    arn.to_string(),
+    "arn:aws:lambda:us-east-2:123456789012:function:my-function"
+);
+```
+
+Placeholders that are not Rust identifiers, such as the IAM policy variable `${aws:username}`,
+are left in the resource unchanged.
+
+```rust
+use aws_arn_macros::arn;
+
+let arn = arn!("arn:aws:s3:::my-bucket/home/${aws:username}");
+assert!(arn.has_variables());
+```
+
+An invalid literal is a compile error.
+
+```rust,compile_fail
+use aws_arn_macros::arn;
+
+let arn = arn!("arn:aws:iam::12345:root");
+```
+
+# Deriving ResourceName Mappings
+
+The `Arn` derive macro maps a struct with named fields to and from `ResourceName` values. The
+`#[arn(service = "...", resource = "...")]` attribute provides the service identifier and a
+template for the resource component, where `{field}` is replaced by the value of the named field.
+The fields `partition`, `region`, and `account` (or `account_id`) are mapped to the corresponding
+ResourceName components; all other fields must appear in the template. Field types must implement
+`Display` and `FromStr`, and fields in the template must be separated by some text.
+
+The derive generates `From<&T>` and `From<T>` for `ResourceName`, `TryFrom<&ResourceName>`, as
+well as `FromStr` and `Display` implementations that use the ResourceName string form.
+
+```rust
+use aws_arn::known::Region;
+use aws_arn::{AccountIdentifier, ResourceName};
+use aws_arn_macros::Arn;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+#[derive(Arn)]
+#[arn(service = "sqs", resource = "{name}")]
+struct QueueRef {
+    region: Region,
+    account: AccountIdentifier,
+    name: String,
+}
+
+let queue = QueueRef::from_str("arn:aws:sqs:us-east-2:123456789012:my-queue").unwrap();
+assert_eq!(queue.region, Region::UsEast2);
+assert_eq!(queue.name, "my-queue");
+
+let arn: ResourceName = queue.into();
+assert_eq!(arn.to_string(), "arn:aws:sqs:us-east-2:123456789012:my-queue");
+```
+*/
+
+#![warn(
+    // ---------- Stylistic
+    future_incompatible,
+    nonstandard_style,
+    rust_2018_idioms,
+    trivial_casts,
+    trivial_numeric_casts,
+    // ---------- Public
+    missing_debug_implementations,
+    missing_docs,
+    unreachable_pub,
+    // ---------- Unsafe
+    unsafe_code,
+    // ---------- Unused
+    unused_extern_crates,
+    unused_import_braces,
+    unused_qualifications,
+    unused_results,
+)]
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, LitStr};
+
+// ------------------------------------------------------------------------------------------------
+// Public Macros
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Construct a `ResourceName` from a string literal that is checked at compile time, see the
+/// crate documentation for details.
+///
+#[proc_macro]
+pub fn arn(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+    literal::expand(&literal)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+///
+/// Derive conversions between a struct and `ResourceName`, see the crate documentation for
+/// details.
+///
+#[proc_macro_derive(Arn, attributes(arn))]
+pub fn derive_arn(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod derive;
+
+mod literal;
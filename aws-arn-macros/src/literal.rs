@@ -0,0 +1,276 @@
+/*!
+Implementation of the `arn!` macro; the literal is split into components, placeholders are
+replaced with stand-in values, and the result is checked with `ResourceName::from_str`.
+*/
+
+use aws_arn::{Error, ResourceName};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use std::ops::Range;
+use std::str::FromStr;
+use syn::LitStr;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+enum Piece {
+    Text(String),
+    Variable(String),
+}
+
+#[derive(Debug)]
+struct Component {
+    name: &'static str,
+    text: String,
+    range: Range<usize>,
+    pieces: Vec<Piece>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const COMPONENT_NAMES: [&str; 6] = [
+    "prefix",
+    "partition",
+    "service",
+    "region",
+    "account",
+    "resource",
+];
+
+const STAND_IN_VALUES: [&str; 6] = ["arn", "aws", "s3", "us-east-1", "123456789012", "x"];
+
+const VARIABLE_START: &str = "${";
+const VARIABLE_END: char = '}';
+
+pub(crate) fn expand(literal: &LitStr) -> syn::Result<TokenStream> {
+    let components = split(literal)?;
+
+    let mut stand_in = Vec::with_capacity(components.len());
+    for (i, component) in components.iter().enumerate() {
+        if i < 5 && component.has_variables() && component.pieces.len() > 1 {
+            return Err(component.error(
+                literal,
+                "a placeholder must make up the whole component".to_string(),
+            ));
+        }
+        stand_in.push(component.stand_in(STAND_IN_VALUES[i]));
+    }
+
+    if let Err(e) = ResourceName::from_str(&stand_in.join(":")) {
+        let faulty = match &e {
+            Error::TooFewComponents => None,
+            Error::MissingPrefix => Some(0),
+            Error::InvalidPartition => Some(1),
+            Error::InvalidIdentifier(s) => (1..4).find(|i| &components[*i].text == s),
+            Error::InvalidAccountId(_) => Some(4),
+            Error::InvalidResource(_) => Some(5),
+            _ => None,
+        };
+        return Err(match faulty {
+            Some(i) => components[i].error(literal, e.to_string()),
+            None => syn::Error::new(literal.span(), format!("invalid ARN literal, {}", e)),
+        });
+    }
+
+    let partition = optional_identifier(&components[1]);
+    let service = identifier(&components[2]);
+    let region = optional_identifier(&components[3]);
+    let account_id = optional_account(&components[4]);
+    let resource = resource(&components[5]);
+
+    Ok(quote! {
+        ::aws_arn::ResourceName {
+            partition: #partition,
+            service: #service,
+            region: #region,
+            account_id: #account_id,
+            resource: #resource,
+        }
+    })
+}
+
+fn split(literal: &LitStr) -> syn::Result<Vec<Component>> {
+    let value = literal.value();
+    let mut components = Vec::with_capacity(COMPONENT_NAMES.len());
+    let mut start = 0;
+    for name in COMPONENT_NAMES.iter().take(5) {
+        match value[start..].find(':') {
+            Some(end) => {
+                components.push(Component::new(name, &value, start..start + end));
+                start += end + 1;
+            }
+            None => {
+                return Err(syn::Error::new(
+                    literal.span(),
+                    format!("invalid ARN literal, {}", Error::TooFewComponents),
+                ))
+            }
+        }
+    }
+    components.push(Component::new(
+        COMPONENT_NAMES[5],
+        &value,
+        start..value.len(),
+    ));
+    Ok(components)
+}
+
+fn is_rust_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            s != "_" && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+fn variable(name: &str) -> Ident {
+    Ident::new(name, Span::call_site())
+}
+
+fn identifier(component: &Component) -> TokenStream {
+    match component.pieces.first() {
+        Some(Piece::Variable(name)) => {
+            let name = variable(name);
+            quote! {
+                ::core::convert::Into::<::aws_arn::Identifier>::into(
+                    ::core::clone::Clone::clone(&#name)
+                )
+            }
+        }
+        _ => {
+            let text = &component.text;
+            quote! { <::aws_arn::Identifier as ::aws_arn::IdentifierLike>::new_unchecked(#text) }
+        }
+    }
+}
+
+fn optional_identifier(component: &Component) -> TokenStream {
+    if component.text.is_empty() {
+        quote! { ::core::option::Option::None }
+    } else {
+        let identifier = identifier(component);
+        quote! { ::core::option::Option::Some(#identifier) }
+    }
+}
+
+fn optional_account(component: &Component) -> TokenStream {
+    match component.pieces.first() {
+        None => quote! { ::core::option::Option::None },
+        Some(Piece::Variable(name)) => {
+            let name = variable(name);
+            quote! {
+                ::core::option::Option::Some(
+                    <::aws_arn::AccountIdentifier as ::core::clone::Clone>::clone(&#name)
+                )
+            }
+        }
+        Some(Piece::Text(text)) => quote! {
+            ::core::option::Option::Some(
+                <::aws_arn::AccountIdentifier as ::aws_arn::IdentifierLike>::new_unchecked(#text)
+            )
+        },
+    }
+}
+
+fn resource(component: &Component) -> TokenStream {
+    if component.has_variables() {
+        let pieces = component.pieces.iter().map(|piece| match piece {
+            Piece::Text(text) => quote! { #text },
+            Piece::Variable(name) => {
+                let name = variable(name);
+                quote! { fragment(&#name) }
+            }
+        });
+        quote! {
+            {
+                fn fragment<T: ::aws_arn::IdentifierLike>(v: &T) -> &str {
+                    v
+                }
+                <::aws_arn::ResourceIdentifier as ::aws_arn::IdentifierLike>::new_unchecked(
+                    &[#(#pieces),*].concat()
+                )
+            }
+        }
+    } else {
+        let text = &component.text;
+        quote! { <::aws_arn::ResourceIdentifier as ::aws_arn::IdentifierLike>::new_unchecked(#text) }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Component {
+    fn new(name: &'static str, value: &str, range: Range<usize>) -> Self {
+        let text = value[range.clone()].to_string();
+        let mut pieces = Vec::new();
+        let mut rest = text.as_str();
+        let mut literal = String::new();
+        while let Some(start) = rest.find(VARIABLE_START) {
+            let after = &rest[start + VARIABLE_START.len()..];
+            match after.find(VARIABLE_END) {
+                Some(end) if is_rust_identifier(&after[..end]) => {
+                    literal.push_str(&rest[..start]);
+                    if !literal.is_empty() {
+                        pieces.push(Piece::Text(std::mem::take(&mut literal)));
+                    }
+                    pieces.push(Piece::Variable(after[..end].to_string()));
+                    rest = &after[end + 1..];
+                }
+                _ => {
+                    literal.push_str(&rest[..start + VARIABLE_START.len()]);
+                    rest = after;
+                }
+            }
+        }
+        literal.push_str(rest);
+        if !literal.is_empty() {
+            pieces.push(Piece::Text(literal));
+        }
+        Self {
+            name,
+            text,
+            range,
+            pieces,
+        }
+    }
+
+    fn has_variables(&self) -> bool {
+        self.pieces.iter().any(|p| matches!(p, Piece::Variable(_)))
+    }
+
+    fn stand_in(&self, value: &str) -> String {
+        self.pieces
+            .iter()
+            .map(|piece| match piece {
+                Piece::Text(text) => text.as_str(),
+                Piece::Variable(_) => value,
+            })
+            .collect()
+    }
+
+    fn error(&self, literal: &LitStr, message: String) -> syn::Error {
+        // Sub-spans are only available on nightly compilers, and only make sense if the
+        // literal contains no escapes; otherwise we fall back to the whole literal.
+        let token = literal.token();
+        let span = if token.to_string() == format!("\"{}\"", literal.value()) {
+            token
+                .subspan(self.range.start + 1..self.range.end + 1)
+                .unwrap_or_else(|| literal.span())
+        } else {
+            literal.span()
+        };
+        syn::Error::new(
+            span,
+            format!(
+                "invalid {} component `{}` in ARN literal, {}",
+                self.name, self.text, message
+            ),
+        )
+    }
+}
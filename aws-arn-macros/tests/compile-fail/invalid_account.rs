@@ -0,0 +1,5 @@
+use aws_arn_macros::arn;
+
+fn main() {
+    let _ = arn!("arn:aws:lambda:us-east-1:12345:function:my-function");
+}
@@ -0,0 +1,5 @@
+use aws_arn_macros::arn;
+
+fn main() {
+    let _ = arn!("arn:azure:s3:::my-bucket");
+}
@@ -0,0 +1,56 @@
+use aws_arn::known::{Partition, Region};
+use aws_arn::{AccountIdentifier, Identifier, IdentifierLike, ResourceIdentifier, ResourceName};
+use aws_arn_macros::arn;
+use std::str::FromStr;
+
+#[test]
+fn test_literal_matches_from_str() {
+    assert_eq!(
+        arn!("arn:aws:s3:::my-bucket"),
+        ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap()
+    );
+    assert_eq!(
+        arn!("arn:aws:iam::aws:policy/ReadOnlyAccess"),
+        ResourceName::from_str("arn:aws:iam::aws:policy/ReadOnlyAccess").unwrap()
+    );
+    assert_eq!(
+        arn!("arn:aws:cloudwatch:us-west-2:123456789012:alarm:Production:LB:High4xx"),
+        ResourceName::from_str(
+            "arn:aws:cloudwatch:us-west-2:123456789012:alarm:Production:LB:High4xx"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_literal_without_partition() {
+    let arn = arn!("arn::s3:::my-bucket");
+    assert_eq!(arn.partition, None);
+    assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket");
+}
+
+#[test]
+fn test_placeholders() {
+    let partition = Partition::AwsChina;
+    let region = Region::UsEast2;
+    let account = AccountIdentifier::from_str("123456789012").unwrap();
+    let layer = Identifier::from_str("my-layer").unwrap();
+    let version = ResourceIdentifier::new_unchecked("3");
+
+    let arn = arn!("arn:${partition}:lambda:${region}:${account}:layer:${layer}:${version}");
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws-cn:lambda:us-east-2:123456789012:layer:my-layer:3"
+    );
+}
+
+#[test]
+fn test_policy_variables_retained() {
+    let bucket = Identifier::from_str("my-bucket").unwrap();
+    let arn = arn!("arn:aws:s3:::${bucket}/home/${aws:username}/*");
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3:::my-bucket/home/${aws:username}/*"
+    );
+    assert!(arn.has_variables());
+}
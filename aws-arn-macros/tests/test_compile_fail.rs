@@ -0,0 +1,5 @@
+#[test]
+fn test_invalid_literals() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile-fail/*.rs");
+}
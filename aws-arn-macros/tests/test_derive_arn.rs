@@ -0,0 +1,95 @@
+use aws_arn::known::{Partition, Region};
+use aws_arn::{AccountIdentifier, Error, ResourceName};
+use aws_arn_macros::Arn;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+#[derive(Arn, Debug, PartialEq)]
+#[arn(service = "sqs", resource = "{name}")]
+struct QueueRef {
+    region: Region,
+    account: AccountIdentifier,
+    name: String,
+}
+
+#[derive(Arn, Debug, PartialEq)]
+#[arn(service = "lambda", resource = "layer:{layer}:{version}")]
+struct LayerVersionRef {
+    partition: Partition,
+    region: Region,
+    account_id: AccountIdentifier,
+    layer: String,
+    version: u32,
+}
+
+#[derive(Arn, Debug, PartialEq)]
+#[arn(service = "s3", resource = "{bucket}/{key}")]
+struct ObjectRef {
+    bucket: String,
+    key: String,
+}
+
+#[test]
+fn test_queue_round_trip() {
+    let queue = QueueRef {
+        region: Region::UsEast2,
+        account: AccountIdentifier::from_str("123456789012").unwrap(),
+        name: "my-queue".to_string(),
+    };
+    let arn: ResourceName = (&queue).into();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:sqs:us-east-2:123456789012:my-queue"
+    );
+    assert_eq!(QueueRef::try_from(&arn).unwrap(), queue);
+    assert_eq!(queue.to_string(), arn.to_string());
+}
+
+#[test]
+fn test_layer_version_from_str() {
+    let layer =
+        LayerVersionRef::from_str("arn:aws-cn:lambda:us-west-2:123456789012:layer:my-layer:3")
+            .unwrap();
+    assert_eq!(layer.partition, Partition::AwsChina);
+    assert_eq!(layer.region, Region::UsWest2);
+    assert_eq!(layer.layer, "my-layer");
+    assert_eq!(layer.version, 3);
+    assert_eq!(
+        layer.to_string(),
+        "arn:aws-cn:lambda:us-west-2:123456789012:layer:my-layer:3"
+    );
+}
+
+#[test]
+fn test_object_without_region() {
+    let object = ObjectRef::from_str("arn:aws:s3:::my-bucket/photos/2024/cat.jpg").unwrap();
+    assert_eq!(object.bucket, "my-bucket");
+    assert_eq!(object.key, "photos/2024/cat.jpg");
+    assert_eq!(
+        ResourceName::from(object).to_string(),
+        "arn:aws:s3:::my-bucket/photos/2024/cat.jpg"
+    );
+}
+
+#[test]
+fn test_mismatches() {
+    assert_eq!(
+        QueueRef::from_str("arn:aws:sns:us-east-2:123456789012:my-topic"),
+        Err(Error::ResourceMismatch {
+            expected: "sqs".to_string(),
+            found: "sns".to_string(),
+        })
+    );
+    assert_eq!(
+        QueueRef::from_str("arn:aws:sqs::123456789012:my-queue"),
+        Err(Error::MissingRegion)
+    );
+    assert_eq!(
+        LayerVersionRef::from_str("arn:aws:lambda:us-west-2:123456789012:layer:my-layer:latest"),
+        Err(Error::InvalidResource("layer:my-layer:latest".to_string()))
+    );
+    assert_eq!(
+        LayerVersionRef::from_str("arn:aws:lambda:us-west-2:123456789012:function:my-function"),
+        Err(Error::InvalidResource("function:my-function".to_string()))
+    );
+}
@@ -0,0 +1,163 @@
+//!
+//! Generates the `known::ManagedPolicy` and `known::ServiceLinkedRole` enums from the vendored
+//! lists in the `data` directory.
+//!
+
+use std::collections::HashSet;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+// ------------------------------------------------------------------------------------------------
+// Main
+// ------------------------------------------------------------------------------------------------
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set by cargo");
+
+    let policies: Vec<(String, String)> = read_lines("data/managed-policies.txt")
+        .into_iter()
+        .map(|line| match line.rsplit_once('/') {
+            Some((path, name)) => (path.to_string(), name.to_string()),
+            None => (String::new(), line),
+        })
+        .collect();
+    fs::write(
+        Path::new(&out_dir).join("managed_policies.rs"),
+        generate(
+            "ManagedPolicy",
+            "AWS managed policies",
+            "path",
+            "The IAM path of this policy, without leading or trailing '/', may be empty.",
+            |(path, name)| {
+                if path.is_empty() {
+                    format!("arn:${{Partition}}:iam::aws:policy/{}", name)
+                } else {
+                    format!("arn:${{Partition}}:iam::aws:policy/{}/{}", path, name)
+                }
+            },
+            &policies,
+        ),
+    )
+    .expect("could not write managed_policies.rs");
+
+    let roles: Vec<(String, String)> = read_lines("data/service-linked-roles.txt")
+        .into_iter()
+        .map(|line| {
+            let (principal, name) = line
+                .split_once(' ')
+                .unwrap_or_else(|| panic!("expected a service principal and name in {:?}", line));
+            (principal.to_string(), name.trim().to_string())
+        })
+        .collect();
+    fs::write(
+        Path::new(&out_dir).join("service_linked_roles.rs"),
+        generate(
+            "ServiceLinkedRole",
+            "service-linked roles",
+            "service_principal",
+            "The service principal that this role is linked to.",
+            |(principal, name)| {
+                format!(
+                    "arn:${{Partition}}:iam::${{Account}}:role/aws-service-role/{}/{}",
+                    principal, name
+                )
+            },
+            &roles,
+        ),
+    )
+    .expect("could not write service_linked_roles.rs");
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn read_lines(file_name: &str) -> Vec<String> {
+    println!("cargo:rerun-if-changed={}", file_name);
+    fs::read_to_string(file_name)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", file_name, e))
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+fn generate<F>(
+    type_name: &str,
+    description: &str,
+    qualifier_fn: &str,
+    qualifier_doc: &str,
+    arn_doc: F,
+    entries: &[(String, String)],
+) -> String
+where
+    F: Fn(&(String, String)) -> String,
+{
+    let mut variants = HashSet::new();
+    let names: Vec<String> = entries
+        .iter()
+        .map(|(_, name)| {
+            let variant = name.replace(['-', '.'], "_");
+            assert!(
+                variants.insert(variant.clone()),
+                "duplicate {} variant {}",
+                type_name,
+                variant
+            );
+            variant
+        })
+        .collect();
+
+    let mut out = String::new();
+    let _ = writeln!(out, "///");
+    let _ = writeln!(
+        out,
+        "/// A list of known {}, generated from a vendored list.",
+        description
+    );
+    let _ = writeln!(out, "///");
+    let _ = writeln!(out, "#[allow(non_camel_case_types)]");
+    let _ = writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]");
+    let _ = writeln!(out, "pub enum {} {{", type_name);
+    for (entry, variant) in entries.iter().zip(names.iter()) {
+        let _ = writeln!(out, "    /// Corresponds to `{}`", arn_doc(entry));
+        let _ = writeln!(out, "    {},", variant);
+        let _ = writeln!(out);
+    }
+    let _ = writeln!(out, "}}");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "impl {} {{", type_name);
+    let _ = writeln!(out, "    /// All of the known values.");
+    let _ = writeln!(out, "    pub const ALL: &[{}] = &[", type_name);
+    for variant in &names {
+        let _ = writeln!(out, "        {}::{},", type_name, variant);
+    }
+    let _ = writeln!(out, "    ];");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    /// The name of this value as used by AWS.");
+    let _ = writeln!(out, "    pub fn name(&self) -> &'static str {{");
+    let _ = writeln!(out, "        match self {{");
+    for ((_, name), variant) in entries.iter().zip(names.iter()) {
+        let _ = writeln!(out, "            {}::{} => {:?},", type_name, variant, name);
+    }
+    let _ = writeln!(out, "        }}");
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    /// {}", qualifier_doc);
+    let _ = writeln!(out, "    pub fn {}(&self) -> &'static str {{", qualifier_fn);
+    let _ = writeln!(out, "        match self {{");
+    for ((qualifier, _), variant) in entries.iter().zip(names.iter()) {
+        let _ = writeln!(
+            out,
+            "            {}::{} => {:?},",
+            type_name, variant, qualifier
+        );
+    }
+    let _ = writeln!(out, "        }}");
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(out, "}}");
+    out
+}
@@ -1,13 +1,75 @@
 /*!
-Provides a set of simple helper functions to make ResourceNames for the Cognito Identity service.
+Provides a set of simple helper functions to make ResourceNames for the Cognito Identity, Cognito
+user pool (`cognito-idp`), and Cognito Sync services.
 
-These resource definitions ae take from the AWS
-[documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazoncognitoidentity.html#amazoncognitoidentity-resources-for-iam-policies).
+These resource definitions ae take from the AWS documentation for
+[Cognito Identity](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazoncognitoidentity.html#amazoncognitoidentity-resources-for-iam-policies),
+[Cognito user pools](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazoncognitouserpools.html#amazoncognitouserpools-resources-for-iam-policies),
+and [Cognito Sync](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazoncognitosync.html#amazoncognitosync-resources-for-iam-policies).
+
+Identity pool and identity IDs are of the form `${Region}:${UUID}`, and user pool IDs of the form
+`${Region}_${Id}`; the region embedded in each ID must match the region of the ResourceName.
 */
 
-use crate::builder::ArnBuilder;
-use crate::known::Service::CognitoIdentity;
-use crate::{AccountIdentifier, Identifier, IdentifierLike, ResourceIdentifier, ResourceName};
+use crate::builder::{
+    account_of, check_name, expect_one_of_services, partition_of, region_of, ArnBuilder,
+    ServiceResource,
+};
+use crate::known::Service::{self, CognitoIdentity, CognitoIdentityProvider, CognitoSync};
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::ops::Deref;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the Cognito ResourceNames constructed by the functions in this module.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum CognitoResource {
+    /// `arn:${Partition}:cognito-identity:${Region}:${Account}:identitypool/${IdentityPoolId}`
+    IdentityPool {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The identity pool ID, of the form `${Region}:${UUID}`.
+        identity_pool_id: ResourceIdentifier,
+    },
+    /// `arn:${Partition}:cognito-idp:${Region}:${Account}:userpool/${UserPoolId}`
+    UserPool {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The user pool ID, of the form `${Region}_${Id}`.
+        user_pool_id: Identifier,
+    },
+    /// `arn:${Partition}:cognito-sync:${Region}:${Account}:identitypool/${IdentityPoolId}/identity/${IdentityId}/dataset/${DatasetName}`
+    Dataset {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The identity pool ID, of the form `${Region}:${UUID}`.
+        identity_pool_id: ResourceIdentifier,
+        /// The identity ID, of the form `${Region}:${UUID}`.
+        identity_id: ResourceIdentifier,
+        /// The dataset name.
+        dataset_name: Identifier,
+    },
+}
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
@@ -20,15 +82,305 @@ pub fn identity_pool(
     partition: Identifier,
     region: Identifier,
     account: AccountIdentifier,
-    identity_pool_id: Identifier,
+    identity_pool_id: ResourceIdentifier,
+) -> Result<ResourceName, Error> {
+    check_identity_id(&identity_pool_id, &region)?;
+    Ok(cognito_arn(
+        CognitoIdentity,
+        partition,
+        region,
+        account,
+        &[
+            ResourceIdentifier::new_unchecked("identitypool"),
+            identity_pool_id,
+        ],
+    ))
+}
+
+///
+/// `arn:${Partition}:cognito-idp:${Region}:${Account}:userpool/${UserPoolId}`
+///
+pub fn user_pool(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    user_pool_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_user_pool_id(&user_pool_id, &region)?;
+    Ok(cognito_arn(
+        CognitoIdentityProvider,
+        partition,
+        region,
+        account,
+        &[
+            ResourceIdentifier::new_unchecked("userpool"),
+            user_pool_id.into(),
+        ],
+    ))
+}
+
+///
+/// `arn:${Partition}:cognito-sync:${Region}:${Account}:identitypool/${IdentityPoolId}/identity/${IdentityId}/dataset/${DatasetName}`
+///
+pub fn dataset(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    identity_pool_id: ResourceIdentifier,
+    identity_id: ResourceIdentifier,
+    dataset_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_identity_id(&identity_pool_id, &region)?;
+    check_identity_id(&identity_id, &region)?;
+    check_name(&dataset_name, 1..=128, |c| {
+        c.is_ascii_alphanumeric() || "_.:-".contains(c)
+    })?;
+    Ok(dataset_arn(
+        partition,
+        region,
+        account,
+        identity_pool_id,
+        identity_id,
+        dataset_name,
+    ))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl TryFrom<&ResourceName> for CognitoResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        let invalid = || Error::InvalidResource(arn.resource.to_string());
+        let service = expect_one_of_services(
+            arn,
+            &[CognitoIdentity, CognitoIdentityProvider, CognitoSync],
+        )?;
+        let partition = partition_of(arn);
+        let region = region_of(arn)?;
+        let account = account_of(arn)?;
+        let parts: Vec<&str> = arn.resource.split('/').collect();
+        match (service, parts.as_slice()) {
+            (CognitoIdentity, ["identitypool", identity_pool_id]) => {
+                let identity_pool_id =
+                    ResourceIdentifier::from_str(identity_pool_id).map_err(|_| invalid())?;
+                check_id_region(&identity_pool_id, ':', &region, arn)?;
+                Ok(CognitoResource::IdentityPool {
+                    partition,
+                    region,
+                    account,
+                    identity_pool_id,
+                })
+            }
+            (CognitoIdentityProvider, ["userpool", user_pool_id]) => {
+                let user_pool_id = Identifier::from_str(user_pool_id).map_err(|_| invalid())?;
+                check_id_region(&user_pool_id, '_', &region, arn)?;
+                Ok(CognitoResource::UserPool {
+                    partition,
+                    region,
+                    account,
+                    user_pool_id,
+                })
+            }
+            (
+                CognitoSync,
+                ["identitypool", identity_pool_id, "identity", identity_id, "dataset", dataset_name],
+            ) => {
+                let identity_pool_id =
+                    ResourceIdentifier::from_str(identity_pool_id).map_err(|_| invalid())?;
+                let identity_id =
+                    ResourceIdentifier::from_str(identity_id).map_err(|_| invalid())?;
+                check_id_region(&identity_pool_id, ':', &region, arn)?;
+                check_id_region(&identity_id, ':', &region, arn)?;
+                Ok(CognitoResource::Dataset {
+                    partition,
+                    region,
+                    account,
+                    identity_pool_id,
+                    identity_id,
+                    dataset_name: Identifier::from_str(dataset_name).map_err(|_| invalid())?,
+                })
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl From<&CognitoResource> for ResourceName {
+    fn from(v: &CognitoResource) -> Self {
+        match v.clone() {
+            CognitoResource::IdentityPool {
+                partition,
+                region,
+                account,
+                identity_pool_id,
+            } => cognito_arn(
+                CognitoIdentity,
+                partition,
+                region,
+                account,
+                &[
+                    ResourceIdentifier::new_unchecked("identitypool"),
+                    identity_pool_id,
+                ],
+            ),
+            CognitoResource::UserPool {
+                partition,
+                region,
+                account,
+                user_pool_id,
+            } => cognito_arn(
+                CognitoIdentityProvider,
+                partition,
+                region,
+                account,
+                &[
+                    ResourceIdentifier::new_unchecked("userpool"),
+                    user_pool_id.into(),
+                ],
+            ),
+            CognitoResource::Dataset {
+                partition,
+                region,
+                account,
+                identity_pool_id,
+                identity_id,
+                dataset_name,
+            } => dataset_arn(
+                partition,
+                region,
+                account,
+                identity_pool_id,
+                identity_id,
+                dataset_name,
+            ),
+        }
+    }
+}
+
+impl From<CognitoResource> for ResourceName {
+    fn from(v: CognitoResource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for CognitoResource {
+    fn service(&self) -> Identifier {
+        match self {
+            CognitoResource::IdentityPool { .. } => CognitoIdentity.into(),
+            CognitoResource::UserPool { .. } => CognitoIdentityProvider.into(),
+            CognitoResource::Dataset { .. } => CognitoSync.into(),
+        }
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            CognitoResource::IdentityPool { .. } => "identitypool",
+            CognitoResource::UserPool { .. } => "userpool",
+            CognitoResource::Dataset { .. } => "dataset",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn cognito_arn(
+    service: Service,
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    resource: &[ResourceIdentifier],
 ) -> ResourceName {
-    ArnBuilder::service_id(CognitoIdentity.into())
+    ArnBuilder::service_id(service.into())
         .in_partition_id(partition)
         .in_region_id(region)
         .owned_by(account)
-        .is(ResourceIdentifier::from_id_path(&[
-            Identifier::new_unchecked("identitypool"),
-            identity_pool_id,
-        ]))
+        .is(ResourceIdentifier::from_path(resource))
         .into()
 }
+
+fn dataset_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    identity_pool_id: ResourceIdentifier,
+    identity_id: ResourceIdentifier,
+    dataset_name: Identifier,
+) -> ResourceName {
+    cognito_arn(
+        CognitoSync,
+        partition,
+        region,
+        account,
+        &[
+            ResourceIdentifier::new_unchecked("identitypool"),
+            identity_pool_id,
+            ResourceIdentifier::new_unchecked("identity"),
+            identity_id,
+            ResourceIdentifier::new_unchecked("dataset"),
+            dataset_name.into(),
+        ],
+    )
+}
+
+fn check_identity_id(id: &ResourceIdentifier, region: &Identifier) -> Result<(), Error> {
+    if id.has_wildcards() {
+        return Ok(());
+    }
+    match id.split_once(':') {
+        Some((id_region, uuid)) if Identifier::is_valid(id_region) => {
+            check_name(uuid, 36..=36, |c| c.is_ascii_hexdigit() || c == '-')?;
+            check_region(id_region, region)
+        }
+        _ => Err(Error::InvalidIdentifier(id.to_string())),
+    }
+}
+
+fn check_user_pool_id(id: &Identifier, region: &Identifier) -> Result<(), Error> {
+    if id.has_wildcards() {
+        return Ok(());
+    }
+    match id.split_once('_') {
+        Some((id_region, suffix))
+            if id.len() <= 55
+                && !id_region.is_empty()
+                && !suffix.is_empty()
+                && suffix.chars().all(|c| c.is_ascii_alphanumeric()) =>
+        {
+            check_region(id_region, region)
+        }
+        _ => Err(Error::InvalidIdentifier(id.to_string())),
+    }
+}
+
+fn check_region(id_region: &str, region: &Identifier) -> Result<(), Error> {
+    if region.has_wildcards() || region.deref() == id_region {
+        Ok(())
+    } else {
+        Err(Error::InvalidRegion)
+    }
+}
+
+// The typed parser only requires that the region embedded in an ID matches the ResourceName.
+fn check_id_region(
+    id: &str,
+    separator: char,
+    region: &Identifier,
+    arn: &ResourceName,
+) -> Result<(), Error> {
+    if id.contains(['*', '?']) {
+        return Ok(());
+    }
+    match id.split_once(separator) {
+        Some((id_region, _)) => check_region(id_region, region),
+        None => Err(Error::InvalidResource(arn.resource.to_string())),
+    }
+}
@@ -0,0 +1,639 @@
+/*!
+Provides a set of simple helper functions to make ResourceNames for the DynamoDB service.
+
+These resource definitions ae take from the AWS
+[documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazondynamodb.html#amazondynamodb-resources-for-iam-policies).
+
+Indexes, streams, backups, and exports are all named relative to their table, and the `table_from`
+function will return the table ResourceName for any of these. Global tables have no region
+component.
+
+A stream is identified by its label, the time it was created, however IAM policies commonly use
+the wildcard `*` in place of the label; so the typed form keeps the label as a resource identifier
+and `DynamoDbResource::stream_label` returns the structured `StreamLabel` when it is not a pattern.
+*/
+
+use crate::builder::{
+    account_of, check_name, expect_service, is_dotted_name_char, partition_of, region_of,
+    ArnBuilder, ServiceResource,
+};
+use crate::known::Service::DynamoDb;
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the DynamoDB ResourceNames constructed by the functions in this module.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum DynamoDbResource {
+    /// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}`
+    Table {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The table name.
+        table_name: Identifier,
+    },
+    /// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}/index/${IndexName}`
+    Index {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The table name.
+        table_name: Identifier,
+        /// The index name.
+        index_name: Identifier,
+    },
+    /// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}/stream/${StreamLabel}`
+    Stream {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The table name.
+        table_name: Identifier,
+        /// The stream label, the time the stream was created, or a pattern.
+        label: ResourceIdentifier,
+    },
+    /// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}/backup/${BackupName}`
+    Backup {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The table name.
+        table_name: Identifier,
+        /// The backup name, for example `01489602797149-73d8d5bc`.
+        backup_name: Identifier,
+    },
+    /// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}/export/${ExportName}`
+    Export {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The table name.
+        table_name: Identifier,
+        /// The export name, for example `01234567890123-a1b2c3d4`.
+        export_name: Identifier,
+    },
+    /// `arn:${Partition}:dynamodb::${Account}:global-table/${GlobalTableName}`
+    GlobalTable {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The global table name.
+        global_table_name: Identifier,
+    },
+}
+
+///
+/// A DynamoDB stream label, the UTC time at which the stream was created, of the form
+/// `2015-05-11T21:21:33.291`. The day is checked against the length of the month, including
+/// leap years.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StreamLabel {
+    /// The year.
+    pub year: u16,
+    /// The month, 1 to 12.
+    pub month: u8,
+    /// The day of the month, 1 to 31.
+    pub day: u8,
+    /// The hour, 0 to 23.
+    pub hour: u8,
+    /// The minute, 0 to 59.
+    pub minute: u8,
+    /// The second, 0 to 59.
+    pub second: u8,
+    /// The millisecond, 0 to 999.
+    pub millisecond: u16,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}`
+///
+pub fn table(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    table_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_table_name(&table_name)?;
+    Ok(table_arn(partition, region, account, table_name, &[]))
+}
+
+///
+/// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}/index/${IndexName}`
+///
+pub fn index(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    table_name: Identifier,
+    index_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_table_name(&table_name)?;
+    check_table_name(&index_name)?;
+    Ok(table_arn(
+        partition,
+        region,
+        account,
+        table_name,
+        &[
+            ResourceIdentifier::new_unchecked("index"),
+            index_name.into(),
+        ],
+    ))
+}
+
+///
+/// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}/stream/${StreamLabel}`
+///
+pub fn stream(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    table_name: Identifier,
+    label: StreamLabel,
+) -> Result<ResourceName, Error> {
+    check_table_name(&table_name)?;
+    Ok(table_arn(
+        partition,
+        region,
+        account,
+        table_name,
+        &[ResourceIdentifier::new_unchecked("stream"), label.into()],
+    ))
+}
+
+///
+/// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}/backup/${BackupName}`
+///
+pub fn backup(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    table_name: Identifier,
+    backup_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_table_name(&table_name)?;
+    check_child_name(&backup_name)?;
+    Ok(table_arn(
+        partition,
+        region,
+        account,
+        table_name,
+        &[
+            ResourceIdentifier::new_unchecked("backup"),
+            backup_name.into(),
+        ],
+    ))
+}
+
+///
+/// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}/export/${ExportName}`
+///
+pub fn export(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    table_name: Identifier,
+    export_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_table_name(&table_name)?;
+    check_child_name(&export_name)?;
+    Ok(table_arn(
+        partition,
+        region,
+        account,
+        table_name,
+        &[
+            ResourceIdentifier::new_unchecked("export"),
+            export_name.into(),
+        ],
+    ))
+}
+
+///
+/// `arn:${Partition}:dynamodb::${Account}:global-table/${GlobalTableName}`
+///
+pub fn global_table(
+    partition: Identifier,
+    account: AccountIdentifier,
+    global_table_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_table_name(&global_table_name)?;
+    Ok(global_table_arn(partition, account, global_table_name))
+}
+
+///
+/// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}`
+///
+/// Return the table ResourceName for a table, index, stream, backup, or export ResourceName,
+/// returns `Error::ResourceMismatch` for a global table or any other ResourceName.
+///
+pub fn table_from(arn: &ResourceName) -> Result<ResourceName, Error> {
+    match DynamoDbResource::try_from(arn)? {
+        DynamoDbResource::Table {
+            partition,
+            region,
+            account,
+            table_name,
+        }
+        | DynamoDbResource::Index {
+            partition,
+            region,
+            account,
+            table_name,
+            ..
+        }
+        | DynamoDbResource::Stream {
+            partition,
+            region,
+            account,
+            table_name,
+            ..
+        }
+        | DynamoDbResource::Backup {
+            partition,
+            region,
+            account,
+            table_name,
+            ..
+        }
+        | DynamoDbResource::Export {
+            partition,
+            region,
+            account,
+            table_name,
+            ..
+        } => Ok(table_arn(partition, region, account, table_name, &[])),
+        other => Err(Error::ResourceMismatch {
+            expected: "table".to_string(),
+            found: other.resource_type_name().to_string(),
+        }),
+    }
+}
+
+///
+/// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}/index/${IndexName}`
+///
+/// Construct an index ResourceName from the table ResourceName `table`, returns
+/// `Error::ResourceMismatch` if it is not a DynamoDB table ResourceName.
+///
+pub fn index_from(table: &ResourceName, index_name: Identifier) -> Result<ResourceName, Error> {
+    let (partition, region, account, table_name) = table_parts(table)?;
+    index(partition, region, account, table_name, index_name)
+}
+
+///
+/// `arn:${Partition}:dynamodb:${Region}:${Account}:table/${TableName}/stream/${StreamLabel}`
+///
+/// Construct a stream ResourceName from the table ResourceName `table`, returns
+/// `Error::ResourceMismatch` if it is not a DynamoDB table ResourceName.
+///
+pub fn stream_from(table: &ResourceName, label: StreamLabel) -> Result<ResourceName, Error> {
+    let (partition, region, account, table_name) = table_parts(table)?;
+    stream(partition, region, account, table_name, label)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl TryFrom<&ResourceName> for DynamoDbResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        expect_service(arn, DynamoDb)?;
+        let partition = partition_of(arn);
+        let invalid = || Error::InvalidResource(arn.resource.to_string());
+        let identifier = |s: &str| Identifier::from_str(s).map_err(|_| invalid());
+        let parts: Vec<&str> = arn.resource.split('/').collect();
+        if let ["global-table", global_table_name] = parts.as_slice() {
+            if arn.region.as_ref().is_some_and(|r| !r.is_empty()) {
+                return Err(Error::RegionNotAllowed);
+            }
+            return Ok(DynamoDbResource::GlobalTable {
+                partition,
+                account: account_of(arn)?,
+                global_table_name: identifier(global_table_name)?,
+            });
+        }
+        let region = region_of(arn)?;
+        let account = account_of(arn)?;
+        match parts.as_slice() {
+            ["table", table_name] => Ok(DynamoDbResource::Table {
+                partition,
+                region,
+                account,
+                table_name: identifier(table_name)?,
+            }),
+            ["table", table_name, "index", index_name] => Ok(DynamoDbResource::Index {
+                partition,
+                region,
+                account,
+                table_name: identifier(table_name)?,
+                index_name: identifier(index_name)?,
+            }),
+            ["table", table_name, "stream", label] => Ok(DynamoDbResource::Stream {
+                partition,
+                region,
+                account,
+                table_name: identifier(table_name)?,
+                label: stream_label(label).map_err(|_| invalid())?,
+            }),
+            ["table", table_name, "backup", backup_name] => Ok(DynamoDbResource::Backup {
+                partition,
+                region,
+                account,
+                table_name: identifier(table_name)?,
+                backup_name: identifier(backup_name)?,
+            }),
+            ["table", table_name, "export", export_name] => Ok(DynamoDbResource::Export {
+                partition,
+                region,
+                account,
+                table_name: identifier(table_name)?,
+                export_name: identifier(export_name)?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl From<&DynamoDbResource> for ResourceName {
+    fn from(v: &DynamoDbResource) -> Self {
+        match v.clone() {
+            DynamoDbResource::Table {
+                partition,
+                region,
+                account,
+                table_name,
+            } => table_arn(partition, region, account, table_name, &[]),
+            DynamoDbResource::Index {
+                partition,
+                region,
+                account,
+                table_name,
+                index_name: name,
+            }
+            | DynamoDbResource::Backup {
+                partition,
+                region,
+                account,
+                table_name,
+                backup_name: name,
+            }
+            | DynamoDbResource::Export {
+                partition,
+                region,
+                account,
+                table_name,
+                export_name: name,
+            } => table_arn(
+                partition,
+                region,
+                account,
+                table_name,
+                &[
+                    ResourceIdentifier::new_unchecked(v.resource_type_name()),
+                    name.into(),
+                ],
+            ),
+            DynamoDbResource::Stream {
+                partition,
+                region,
+                account,
+                table_name,
+                label,
+            } => table_arn(
+                partition,
+                region,
+                account,
+                table_name,
+                &[ResourceIdentifier::new_unchecked("stream"), label],
+            ),
+            DynamoDbResource::GlobalTable {
+                partition,
+                account,
+                global_table_name,
+            } => global_table_arn(partition, account, global_table_name),
+        }
+    }
+}
+
+impl From<DynamoDbResource> for ResourceName {
+    fn from(v: DynamoDbResource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for DynamoDbResource {
+    fn service(&self) -> Identifier {
+        DynamoDb.into()
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            DynamoDbResource::Table { .. } => "table",
+            DynamoDbResource::Index { .. } => "index",
+            DynamoDbResource::Stream { .. } => "stream",
+            DynamoDbResource::Backup { .. } => "backup",
+            DynamoDbResource::Export { .. } => "export",
+            DynamoDbResource::GlobalTable { .. } => "global-table",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+impl DynamoDbResource {
+    ///
+    /// Return the structured stream label of a stream, or `None` if this is not a stream or the
+    /// label is a pattern such as `*`.
+    ///
+    pub fn stream_label(&self) -> Option<StreamLabel> {
+        match self {
+            DynamoDbResource::Stream { label, .. } => StreamLabel::from_str(label).ok(),
+            _ => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for StreamLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second, self.millisecond
+        )
+    }
+}
+
+impl FromStr for StreamLabel {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidIdentifier(s.to_string());
+        let bytes = s.as_bytes();
+        let separators = [
+            (4, b'-'),
+            (7, b'-'),
+            (10, b'T'),
+            (13, b':'),
+            (16, b':'),
+            (19, b'.'),
+        ];
+        if !s.is_ascii() || bytes.len() != 23 || separators.iter().any(|(i, c)| bytes[*i] != *c) {
+            return Err(invalid());
+        }
+        let number = |from: usize, to: usize| -> Result<u16, Error> {
+            let digits = &s[from..to];
+            if digits.chars().all(|c| c.is_ascii_digit()) {
+                digits.parse().map_err(|_| invalid())
+            } else {
+                Err(invalid())
+            }
+        };
+        let label = StreamLabel {
+            year: number(0, 4)?,
+            month: number(5, 7)? as u8,
+            day: number(8, 10)? as u8,
+            hour: number(11, 13)? as u8,
+            minute: number(14, 16)? as u8,
+            second: number(17, 19)? as u8,
+            millisecond: number(20, 23)?,
+        };
+        if (1..=12).contains(&label.month)
+            && (1..=days_in_month(label.year, label.month)).contains(&label.day)
+            && label.hour < 24
+            && label.minute < 60
+            && label.second < 60
+        {
+            Ok(label)
+        } else {
+            Err(invalid())
+        }
+    }
+}
+
+impl From<StreamLabel> for ResourceIdentifier {
+    fn from(v: StreamLabel) -> Self {
+        ResourceIdentifier::new_unchecked(&v.to_string())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn table_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    table_name: Identifier,
+    child: &[ResourceIdentifier],
+) -> ResourceName {
+    let mut resource = vec![
+        ResourceIdentifier::new_unchecked("table"),
+        table_name.into(),
+    ];
+    resource.extend_from_slice(child);
+    ArnBuilder::service_id(DynamoDb.into())
+        .in_partition_id(partition)
+        .in_region_id(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_path(&resource))
+        .into()
+}
+
+fn global_table_arn(
+    partition: Identifier,
+    account: AccountIdentifier,
+    global_table_name: Identifier,
+) -> ResourceName {
+    ArnBuilder::service_id(DynamoDb.into())
+        .in_partition_id(partition)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("global-table"),
+            global_table_name,
+        ]))
+        .into()
+}
+
+fn table_parts(
+    table: &ResourceName,
+) -> Result<(Identifier, Identifier, AccountIdentifier, Identifier), Error> {
+    match DynamoDbResource::try_from(table)? {
+        DynamoDbResource::Table {
+            partition,
+            region,
+            account,
+            table_name,
+        } => Ok((partition, region, account, table_name)),
+        other => Err(Error::ResourceMismatch {
+            expected: "table".to_string(),
+            found: other.resource_type_name().to_string(),
+        }),
+    }
+}
+
+// A stream label in a parsed ResourceName may be a pattern, otherwise it must be a valid label.
+fn stream_label(label: &str) -> Result<ResourceIdentifier, Error> {
+    let label = ResourceIdentifier::from_str(label)?;
+    if !label.has_wildcards() {
+        let _ = StreamLabel::from_str(&label)?;
+    }
+    Ok(label)
+}
+
+// `is_multiple_of` would raise the minimum supported Rust version to 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn check_table_name(name: &Identifier) -> Result<(), Error> {
+    check_name(name, 3..=255, is_dotted_name_char)
+}
+
+fn check_child_name(name: &Identifier) -> Result<(), Error> {
+    check_name(name, 1..=64, |c| c.is_ascii_alphanumeric() || c == '-')
+}
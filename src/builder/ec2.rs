@@ -0,0 +1,523 @@
+/*!
+Provides a set of simple helper functions to make ResourceNames for the EC2 service.
+
+These resource definitions ae take from the AWS
+[documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazonec2.html#amazonec2-resources-for-iam-policies).
+
+EC2 resource IDs are a resource-specific prefix, such as `i-` for instances, followed by either 8
+or 17 lowercase hexadecimal digits. Images and snapshots that are public, or owned by AWS, have no
+account component and so the account is optional for these resource types.
+*/
+
+use crate::builder::{
+    account_of, expect_service, partition_of, region_of, ArnBuilder, ServiceResource,
+};
+use crate::known::Service::Ec2;
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the EC2 ResourceNames constructed by the functions in this module.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum Ec2Resource {
+    /// `arn:${Partition}:ec2:${Region}:${Account}:instance/${InstanceId}`
+    Instance {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The instance ID, `i-` followed by 8 or 17 hex digits.
+        instance_id: Identifier,
+    },
+    /// `arn:${Partition}:ec2:${Region}:${Account}:volume/${VolumeId}`
+    Volume {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The volume ID, `vol-` followed by 8 or 17 hex digits.
+        volume_id: Identifier,
+    },
+    /// `arn:${Partition}:ec2:${Region}:${Account}:snapshot/${SnapshotId}`
+    Snapshot {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component, not present for public snapshots.
+        account: Option<AccountIdentifier>,
+        /// The snapshot ID, `snap-` followed by 8 or 17 hex digits.
+        snapshot_id: Identifier,
+    },
+    /// `arn:${Partition}:ec2:${Region}:${Account}:vpc/${VpcId}`
+    Vpc {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The VPC ID, `vpc-` followed by 8 or 17 hex digits.
+        vpc_id: Identifier,
+    },
+    /// `arn:${Partition}:ec2:${Region}:${Account}:subnet/${SubnetId}`
+    Subnet {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The subnet ID, `subnet-` followed by 8 or 17 hex digits.
+        subnet_id: Identifier,
+    },
+    /// `arn:${Partition}:ec2:${Region}:${Account}:security-group/${SecurityGroupId}`
+    SecurityGroup {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The security group ID, `sg-` followed by 8 or 17 hex digits.
+        security_group_id: Identifier,
+    },
+    /// `arn:${Partition}:ec2:${Region}:${Account}:network-interface/${NetworkInterfaceId}`
+    NetworkInterface {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The network interface ID, `eni-` followed by 8 or 17 hex digits.
+        network_interface_id: Identifier,
+    },
+    /// `arn:${Partition}:ec2:${Region}:${Account}:launch-template/${LaunchTemplateId}`
+    LaunchTemplate {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The launch template ID, `lt-` followed by 8 or 17 hex digits.
+        launch_template_id: Identifier,
+    },
+    /// `arn:${Partition}:ec2:${Region}:${Account}:image/${ImageId}`
+    Image {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component, not present for public images.
+        account: Option<AccountIdentifier>,
+        /// The image ID, `ami-` followed by 8 or 17 hex digits.
+        image_id: Identifier,
+    },
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// `arn:${Partition}:ec2:${Region}:${Account}:instance/${InstanceId}`
+///
+pub fn instance(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    instance_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_id(&instance_id, PREFIX_INSTANCE)?;
+    Ok(ec2_arn(
+        partition,
+        region,
+        Some(account),
+        "instance",
+        instance_id,
+    ))
+}
+
+///
+/// `arn:${Partition}:ec2:${Region}:${Account}:volume/${VolumeId}`
+///
+pub fn volume(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    volume_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_id(&volume_id, PREFIX_VOLUME)?;
+    Ok(ec2_arn(
+        partition,
+        region,
+        Some(account),
+        "volume",
+        volume_id,
+    ))
+}
+
+///
+/// `arn:${Partition}:ec2:${Region}:${Account}:snapshot/${SnapshotId}`, or
+/// `arn:${Partition}:ec2:${Region}::snapshot/${SnapshotId}` for a public snapshot.
+///
+pub fn snapshot(
+    partition: Identifier,
+    region: Identifier,
+    account: Option<AccountIdentifier>,
+    snapshot_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_id(&snapshot_id, PREFIX_SNAPSHOT)?;
+    Ok(ec2_arn(partition, region, account, "snapshot", snapshot_id))
+}
+
+///
+/// `arn:${Partition}:ec2:${Region}:${Account}:vpc/${VpcId}`
+///
+pub fn vpc(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    vpc_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_id(&vpc_id, PREFIX_VPC)?;
+    Ok(ec2_arn(partition, region, Some(account), "vpc", vpc_id))
+}
+
+///
+/// `arn:${Partition}:ec2:${Region}:${Account}:subnet/${SubnetId}`
+///
+pub fn subnet(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    subnet_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_id(&subnet_id, PREFIX_SUBNET)?;
+    Ok(ec2_arn(
+        partition,
+        region,
+        Some(account),
+        "subnet",
+        subnet_id,
+    ))
+}
+
+///
+/// `arn:${Partition}:ec2:${Region}:${Account}:security-group/${SecurityGroupId}`
+///
+pub fn security_group(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    security_group_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_id(&security_group_id, PREFIX_SECURITY_GROUP)?;
+    Ok(ec2_arn(
+        partition,
+        region,
+        Some(account),
+        "security-group",
+        security_group_id,
+    ))
+}
+
+///
+/// `arn:${Partition}:ec2:${Region}:${Account}:network-interface/${NetworkInterfaceId}`
+///
+pub fn network_interface(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    network_interface_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_id(&network_interface_id, PREFIX_NETWORK_INTERFACE)?;
+    Ok(ec2_arn(
+        partition,
+        region,
+        Some(account),
+        "network-interface",
+        network_interface_id,
+    ))
+}
+
+///
+/// `arn:${Partition}:ec2:${Region}:${Account}:launch-template/${LaunchTemplateId}`
+///
+pub fn launch_template(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    launch_template_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_id(&launch_template_id, PREFIX_LAUNCH_TEMPLATE)?;
+    Ok(ec2_arn(
+        partition,
+        region,
+        Some(account),
+        "launch-template",
+        launch_template_id,
+    ))
+}
+
+///
+/// `arn:${Partition}:ec2:${Region}:${Account}:image/${ImageId}`, or
+/// `arn:${Partition}:ec2:${Region}::image/${ImageId}` for a public image.
+///
+pub fn image(
+    partition: Identifier,
+    region: Identifier,
+    account: Option<AccountIdentifier>,
+    image_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_id(&image_id, PREFIX_IMAGE)?;
+    Ok(ec2_arn(partition, region, account, "image", image_id))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl TryFrom<&ResourceName> for Ec2Resource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        expect_service(arn, Ec2)?;
+        let partition = partition_of(arn);
+        let region = region_of(arn)?;
+        let invalid = || Error::InvalidResource(arn.resource.to_string());
+        let (resource_type, id) = arn.resource.split_once('/').ok_or_else(invalid)?;
+        let prefix = match resource_type {
+            "instance" => PREFIX_INSTANCE,
+            "volume" => PREFIX_VOLUME,
+            "snapshot" => PREFIX_SNAPSHOT,
+            "vpc" => PREFIX_VPC,
+            "subnet" => PREFIX_SUBNET,
+            "security-group" => PREFIX_SECURITY_GROUP,
+            "network-interface" => PREFIX_NETWORK_INTERFACE,
+            "launch-template" => PREFIX_LAUNCH_TEMPLATE,
+            "image" => PREFIX_IMAGE,
+            _ => return Err(invalid()),
+        };
+        let id = Identifier::from_str(id).map_err(|_| invalid())?;
+        check_id(&id, prefix).map_err(|_| invalid())?;
+        match resource_type {
+            "snapshot" => Ok(Ec2Resource::Snapshot {
+                partition,
+                region,
+                account: arn.account_id.clone().filter(|a| !a.is_empty()),
+                snapshot_id: id,
+            }),
+            "image" => Ok(Ec2Resource::Image {
+                partition,
+                region,
+                account: arn.account_id.clone().filter(|a| !a.is_empty()),
+                image_id: id,
+            }),
+            _ => {
+                let account = account_of(arn)?;
+                Ok(match resource_type {
+                    "instance" => Ec2Resource::Instance {
+                        partition,
+                        region,
+                        account,
+                        instance_id: id,
+                    },
+                    "volume" => Ec2Resource::Volume {
+                        partition,
+                        region,
+                        account,
+                        volume_id: id,
+                    },
+                    "vpc" => Ec2Resource::Vpc {
+                        partition,
+                        region,
+                        account,
+                        vpc_id: id,
+                    },
+                    "subnet" => Ec2Resource::Subnet {
+                        partition,
+                        region,
+                        account,
+                        subnet_id: id,
+                    },
+                    "security-group" => Ec2Resource::SecurityGroup {
+                        partition,
+                        region,
+                        account,
+                        security_group_id: id,
+                    },
+                    "network-interface" => Ec2Resource::NetworkInterface {
+                        partition,
+                        region,
+                        account,
+                        network_interface_id: id,
+                    },
+                    _ => Ec2Resource::LaunchTemplate {
+                        partition,
+                        region,
+                        account,
+                        launch_template_id: id,
+                    },
+                })
+            }
+        }
+    }
+}
+
+impl From<&Ec2Resource> for ResourceName {
+    fn from(v: &Ec2Resource) -> Self {
+        let resource_type = v.resource_type_name();
+        match v.clone() {
+            Ec2Resource::Instance {
+                partition,
+                region,
+                account,
+                instance_id: id,
+            }
+            | Ec2Resource::Volume {
+                partition,
+                region,
+                account,
+                volume_id: id,
+            }
+            | Ec2Resource::Vpc {
+                partition,
+                region,
+                account,
+                vpc_id: id,
+            }
+            | Ec2Resource::Subnet {
+                partition,
+                region,
+                account,
+                subnet_id: id,
+            }
+            | Ec2Resource::SecurityGroup {
+                partition,
+                region,
+                account,
+                security_group_id: id,
+            }
+            | Ec2Resource::NetworkInterface {
+                partition,
+                region,
+                account,
+                network_interface_id: id,
+            }
+            | Ec2Resource::LaunchTemplate {
+                partition,
+                region,
+                account,
+                launch_template_id: id,
+            } => ec2_arn(partition, region, Some(account), resource_type, id),
+            Ec2Resource::Snapshot {
+                partition,
+                region,
+                account,
+                snapshot_id: id,
+            }
+            | Ec2Resource::Image {
+                partition,
+                region,
+                account,
+                image_id: id,
+            } => ec2_arn(partition, region, account, resource_type, id),
+        }
+    }
+}
+
+impl From<Ec2Resource> for ResourceName {
+    fn from(v: Ec2Resource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for Ec2Resource {
+    fn service(&self) -> Identifier {
+        Ec2.into()
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            Ec2Resource::Instance { .. } => "instance",
+            Ec2Resource::Volume { .. } => "volume",
+            Ec2Resource::Snapshot { .. } => "snapshot",
+            Ec2Resource::Vpc { .. } => "vpc",
+            Ec2Resource::Subnet { .. } => "subnet",
+            Ec2Resource::SecurityGroup { .. } => "security-group",
+            Ec2Resource::NetworkInterface { .. } => "network-interface",
+            Ec2Resource::LaunchTemplate { .. } => "launch-template",
+            Ec2Resource::Image { .. } => "image",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const PREFIX_INSTANCE: &str = "i-";
+const PREFIX_VOLUME: &str = "vol-";
+const PREFIX_SNAPSHOT: &str = "snap-";
+const PREFIX_VPC: &str = "vpc-";
+const PREFIX_SUBNET: &str = "subnet-";
+const PREFIX_SECURITY_GROUP: &str = "sg-";
+const PREFIX_NETWORK_INTERFACE: &str = "eni-";
+const PREFIX_LAUNCH_TEMPLATE: &str = "lt-";
+const PREFIX_IMAGE: &str = "ami-";
+
+fn ec2_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: Option<AccountIdentifier>,
+    resource_type: &str,
+    id: Identifier,
+) -> ResourceName {
+    let mut builder = ArnBuilder::service_id(Ec2.into());
+    let _ = builder.in_partition_id(partition).in_region_id(region);
+    if let Some(account) = account {
+        let _ = builder.owned_by(account);
+    }
+    builder
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked(resource_type),
+            id,
+        ]))
+        .into()
+}
+
+fn check_id(id: &Identifier, prefix: &str) -> Result<(), Error> {
+    if id.has_wildcards() {
+        return Ok(());
+    }
+    match id.strip_prefix(prefix) {
+        Some(hex)
+            if (hex.len() == 8 || hex.len() == 17)
+                && hex
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)) =>
+        {
+            Ok(())
+        }
+        _ => Err(Error::InvalidIdentifier(id.to_string())),
+    }
+}
@@ -0,0 +1,708 @@
+/*!
+Provides a set of simple helper functions to make ResourceNames for the EventBridge, EventBridge
+Pipes, and EventBridge Scheduler services.
+
+These resource definitions ae take from the AWS documentation for
+[EventBridge](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazoneventbridge.html#amazoneventbridge-resources-for-iam-policies),
+[EventBridge Pipes](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazoneventbridgepipes.html#amazoneventbridgepipes-resources-for-iam-policies),
+and [EventBridge Scheduler](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazoneventbridgescheduler.html#amazoneventbridgescheduler-resources-for-iam-policies).
+
+A rule on the default event bus has the resource `rule/${RuleName}`, while a rule on any other bus
+has the extra segment `rule/${EventBusName}/${RuleName}`; a rule naming the `default` bus is
+normalized to the first form. Event bus names for partner event sources, such as
+`aws.partner/example.com/123/orders`, include `/` characters.
+*/
+
+use crate::builder::{
+    account_of, check_name, expect_one_of_services, is_dotted_name_char, partition_of, region_of,
+    ArnBuilder, ServiceResource,
+};
+use crate::known::Service::{self, EventBridge, EventBridgePipes, EventBridgeScheduler};
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::ops::Deref;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the EventBridge ResourceNames constructed by the functions in this module.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum EventsResource {
+    /// `arn:${Partition}:events:${Region}:${Account}:event-bus/${EventBusName}`
+    EventBus {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The event bus name.
+        name: ResourceIdentifier,
+    },
+    /// `arn:${Partition}:events:${Region}:${Account}:rule/[${EventBusName}/]${RuleName}`
+    Rule {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The event bus name, `None` for the default event bus.
+        event_bus_name: Option<ResourceIdentifier>,
+        /// The rule name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:events:${Region}:${Account}:archive/${ArchiveName}`
+    Archive {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The archive name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:events:${Region}:${Account}:replay/${ReplayName}`
+    Replay {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The replay name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:events:${Region}:${Account}:connection/${ConnectionName}[/${UUID}]`
+    Connection {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The connection name.
+        name: Identifier,
+        /// The UUID added by EventBridge, if present.
+        uuid: Option<Identifier>,
+    },
+    /// `arn:${Partition}:events:${Region}:${Account}:api-destination/${ApiDestinationName}[/${UUID}]`
+    ApiDestination {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The API destination name.
+        name: Identifier,
+        /// The UUID added by EventBridge, if present.
+        uuid: Option<Identifier>,
+    },
+    /// `arn:${Partition}:pipes:${Region}:${Account}:pipe/${PipeName}`
+    Pipe {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The pipe name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:scheduler:${Region}:${Account}:schedule/${GroupName}/${ScheduleName}`
+    Schedule {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The schedule group name, `default` for the default group.
+        group_name: Identifier,
+        /// The schedule name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:scheduler:${Region}:${Account}:schedule-group/${GroupName}`
+    ScheduleGroup {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The schedule group name.
+        name: Identifier,
+    },
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// `arn:${Partition}:events:${Region}:${Account}:event-bus/${EventBusName}`
+///
+pub fn event_bus(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: ResourceIdentifier,
+) -> Result<ResourceName, Error> {
+    check_event_bus_name(&name)?;
+    Ok(events_arn(
+        EventBridge,
+        partition,
+        region,
+        account,
+        &[ResourceIdentifier::new_unchecked("event-bus"), name],
+    ))
+}
+
+///
+/// `arn:${Partition}:events:${Region}:${Account}:event-bus/${EventBusName}`
+///
+/// Complete either of the forms of event bus accepted by the EventBridge API, using the provided
+/// partition, region, and account where they are not included:
+///
+/// * an event bus name, `orders`,
+/// * an event bus ResourceName, which is only checked to be an event bus, and may be in another
+///   account.
+///
+pub fn event_bus_from_partial(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    event_bus_name: &str,
+) -> Result<ResourceName, Error> {
+    if event_bus_name.starts_with("arn:") {
+        let arn = ResourceName::from_str(event_bus_name)?;
+        return match EventsResource::try_from(&arn)? {
+            bus @ EventsResource::EventBus { .. } => Ok(bus.into()),
+            other => Err(Error::ResourceMismatch {
+                expected: "event-bus".to_string(),
+                found: other.resource_type_name().to_string(),
+            }),
+        };
+    }
+    event_bus(
+        partition,
+        region,
+        account,
+        ResourceIdentifier::from_str(event_bus_name)?,
+    )
+}
+
+///
+/// `arn:${Partition}:events:${Region}:${Account}:rule/[${EventBusName}/]${RuleName}`
+///
+/// The rule is on the default event bus if `event_bus_name` is `None` or `default`.
+///
+pub fn rule(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    event_bus_name: Option<ResourceIdentifier>,
+    name: Identifier,
+) -> Result<ResourceName, Error> {
+    if let Some(event_bus_name) = &event_bus_name {
+        check_event_bus_name(event_bus_name)?;
+    }
+    check_name(&name, 1..=64, is_dotted_name_char)?;
+    Ok(rule_arn(partition, region, account, event_bus_name, name))
+}
+
+///
+/// Return the event bus ResourceName for a rule ResourceName; an event bus ResourceName is
+/// returned unchanged.
+///
+pub fn event_bus_from(arn: &ResourceName) -> Result<ResourceName, Error> {
+    match EventsResource::try_from(arn)? {
+        EventsResource::EventBus { .. } => Ok(arn.clone()),
+        EventsResource::Rule {
+            partition,
+            region,
+            account,
+            event_bus_name,
+            ..
+        } => Ok(events_arn(
+            EventBridge,
+            partition,
+            region,
+            account,
+            &[
+                ResourceIdentifier::new_unchecked("event-bus"),
+                event_bus_name
+                    .unwrap_or_else(|| ResourceIdentifier::new_unchecked(DEFAULT_EVENT_BUS)),
+            ],
+        )),
+        other => Err(Error::ResourceMismatch {
+            expected: "rule".to_string(),
+            found: other.resource_type_name().to_string(),
+        }),
+    }
+}
+
+///
+/// `arn:${Partition}:events:${Region}:${Account}:archive/${ArchiveName}`
+///
+pub fn archive(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&name, 1..=48, is_dotted_name_char)?;
+    Ok(typed_arn(
+        EventBridge,
+        partition,
+        region,
+        account,
+        "archive",
+        &[name],
+    ))
+}
+
+///
+/// `arn:${Partition}:events:${Region}:${Account}:replay/${ReplayName}`
+///
+pub fn replay(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&name, 1..=64, is_dotted_name_char)?;
+    Ok(typed_arn(
+        EventBridge,
+        partition,
+        region,
+        account,
+        "replay",
+        &[name],
+    ))
+}
+
+///
+/// `arn:${Partition}:events:${Region}:${Account}:connection/${ConnectionName}[/${UUID}]`
+///
+pub fn connection(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: Identifier,
+    uuid: Option<Identifier>,
+) -> Result<ResourceName, Error> {
+    check_name(&name, 1..=64, is_dotted_name_char)?;
+    if let Some(uuid) = &uuid {
+        check_uuid(uuid)?;
+    }
+    Ok(typed_arn(
+        EventBridge,
+        partition,
+        region,
+        account,
+        "connection",
+        &with_uuid(name, uuid),
+    ))
+}
+
+///
+/// `arn:${Partition}:events:${Region}:${Account}:api-destination/${ApiDestinationName}[/${UUID}]`
+///
+pub fn api_destination(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: Identifier,
+    uuid: Option<Identifier>,
+) -> Result<ResourceName, Error> {
+    check_name(&name, 1..=64, is_dotted_name_char)?;
+    if let Some(uuid) = &uuid {
+        check_uuid(uuid)?;
+    }
+    Ok(typed_arn(
+        EventBridge,
+        partition,
+        region,
+        account,
+        "api-destination",
+        &with_uuid(name, uuid),
+    ))
+}
+
+///
+/// `arn:${Partition}:pipes:${Region}:${Account}:pipe/${PipeName}`
+///
+pub fn pipe(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&name, 1..=64, is_dotted_name_char)?;
+    Ok(typed_arn(
+        EventBridgePipes,
+        partition,
+        region,
+        account,
+        "pipe",
+        &[name],
+    ))
+}
+
+///
+/// `arn:${Partition}:scheduler:${Region}:${Account}:schedule/${GroupName}/${ScheduleName}`
+///
+pub fn schedule(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    group_name: Identifier,
+    name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&group_name, 1..=64, is_dotted_name_char)?;
+    check_name(&name, 1..=64, is_dotted_name_char)?;
+    Ok(typed_arn(
+        EventBridgeScheduler,
+        partition,
+        region,
+        account,
+        "schedule",
+        &[group_name, name],
+    ))
+}
+
+///
+/// `arn:${Partition}:scheduler:${Region}:${Account}:schedule-group/${GroupName}`
+///
+pub fn schedule_group(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&name, 1..=64, is_dotted_name_char)?;
+    Ok(typed_arn(
+        EventBridgeScheduler,
+        partition,
+        region,
+        account,
+        "schedule-group",
+        &[name],
+    ))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const DEFAULT_EVENT_BUS: &str = "default";
+
+impl TryFrom<&ResourceName> for EventsResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        let service =
+            expect_one_of_services(arn, &[EventBridge, EventBridgePipes, EventBridgeScheduler])?;
+        let partition = partition_of(arn);
+        let region = region_of(arn)?;
+        let account = account_of(arn)?;
+        let invalid = || Error::InvalidResource(arn.resource.to_string());
+        let id = |s: &str| Identifier::from_str(s).map_err(|_| invalid());
+        let (resource_type, rest) = arn.resource.split_once('/').ok_or_else(invalid)?;
+        match (service, resource_type) {
+            (EventBridge, "event-bus") => Ok(EventsResource::EventBus {
+                partition,
+                region,
+                account,
+                name: ResourceIdentifier::from_str(rest).map_err(|_| invalid())?,
+            }),
+            (EventBridge, "rule") => {
+                let (event_bus_name, name) = match rest.rsplit_once('/') {
+                    Some((DEFAULT_EVENT_BUS, name)) => (None, name),
+                    Some((event_bus_name, name)) => (
+                        Some(ResourceIdentifier::from_str(event_bus_name).map_err(|_| invalid())?),
+                        name,
+                    ),
+                    None => (None, rest),
+                };
+                Ok(EventsResource::Rule {
+                    partition,
+                    region,
+                    account,
+                    event_bus_name,
+                    name: id(name)?,
+                })
+            }
+            (EventBridge, "archive") => Ok(EventsResource::Archive {
+                partition,
+                region,
+                account,
+                name: id(rest)?,
+            }),
+            (EventBridge, "replay") => Ok(EventsResource::Replay {
+                partition,
+                region,
+                account,
+                name: id(rest)?,
+            }),
+            (EventBridge, "connection") | (EventBridge, "api-destination") => {
+                let (name, uuid) = match rest.split_once('/') {
+                    Some((name, uuid)) => (id(name)?, Some(id(uuid)?)),
+                    None => (id(rest)?, None),
+                };
+                if resource_type == "connection" {
+                    Ok(EventsResource::Connection {
+                        partition,
+                        region,
+                        account,
+                        name,
+                        uuid,
+                    })
+                } else {
+                    Ok(EventsResource::ApiDestination {
+                        partition,
+                        region,
+                        account,
+                        name,
+                        uuid,
+                    })
+                }
+            }
+            (EventBridgePipes, "pipe") => Ok(EventsResource::Pipe {
+                partition,
+                region,
+                account,
+                name: id(rest)?,
+            }),
+            (EventBridgeScheduler, "schedule") => {
+                let (group_name, name) = rest.split_once('/').ok_or_else(invalid)?;
+                Ok(EventsResource::Schedule {
+                    partition,
+                    region,
+                    account,
+                    group_name: id(group_name)?,
+                    name: id(name)?,
+                })
+            }
+            (EventBridgeScheduler, "schedule-group") => Ok(EventsResource::ScheduleGroup {
+                partition,
+                region,
+                account,
+                name: id(rest)?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl From<&EventsResource> for ResourceName {
+    fn from(v: &EventsResource) -> Self {
+        match v.clone() {
+            EventsResource::EventBus {
+                partition,
+                region,
+                account,
+                name,
+            } => events_arn(
+                EventBridge,
+                partition,
+                region,
+                account,
+                &[ResourceIdentifier::new_unchecked("event-bus"), name],
+            ),
+            EventsResource::Rule {
+                partition,
+                region,
+                account,
+                event_bus_name,
+                name,
+            } => rule_arn(partition, region, account, event_bus_name, name),
+            EventsResource::Archive {
+                partition,
+                region,
+                account,
+                name,
+            } => typed_arn(EventBridge, partition, region, account, "archive", &[name]),
+            EventsResource::Replay {
+                partition,
+                region,
+                account,
+                name,
+            } => typed_arn(EventBridge, partition, region, account, "replay", &[name]),
+            EventsResource::Connection {
+                partition,
+                region,
+                account,
+                name,
+                uuid,
+            } => typed_arn(
+                EventBridge,
+                partition,
+                region,
+                account,
+                "connection",
+                &with_uuid(name, uuid),
+            ),
+            EventsResource::ApiDestination {
+                partition,
+                region,
+                account,
+                name,
+                uuid,
+            } => typed_arn(
+                EventBridge,
+                partition,
+                region,
+                account,
+                "api-destination",
+                &with_uuid(name, uuid),
+            ),
+            EventsResource::Pipe {
+                partition,
+                region,
+                account,
+                name,
+            } => typed_arn(
+                EventBridgePipes,
+                partition,
+                region,
+                account,
+                "pipe",
+                &[name],
+            ),
+            EventsResource::Schedule {
+                partition,
+                region,
+                account,
+                group_name,
+                name,
+            } => typed_arn(
+                EventBridgeScheduler,
+                partition,
+                region,
+                account,
+                "schedule",
+                &[group_name, name],
+            ),
+            EventsResource::ScheduleGroup {
+                partition,
+                region,
+                account,
+                name,
+            } => typed_arn(
+                EventBridgeScheduler,
+                partition,
+                region,
+                account,
+                "schedule-group",
+                &[name],
+            ),
+        }
+    }
+}
+
+impl From<EventsResource> for ResourceName {
+    fn from(v: EventsResource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for EventsResource {
+    fn service(&self) -> Identifier {
+        match self {
+            EventsResource::Pipe { .. } => EventBridgePipes.into(),
+            EventsResource::Schedule { .. } | EventsResource::ScheduleGroup { .. } => {
+                EventBridgeScheduler.into()
+            }
+            _ => EventBridge.into(),
+        }
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            EventsResource::EventBus { .. } => "event-bus",
+            EventsResource::Rule { .. } => "rule",
+            EventsResource::Archive { .. } => "archive",
+            EventsResource::Replay { .. } => "replay",
+            EventsResource::Connection { .. } => "connection",
+            EventsResource::ApiDestination { .. } => "api-destination",
+            EventsResource::Pipe { .. } => "pipe",
+            EventsResource::Schedule { .. } => "schedule",
+            EventsResource::ScheduleGroup { .. } => "schedule-group",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn events_arn(
+    service: Service,
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    resource: &[ResourceIdentifier],
+) -> ResourceName {
+    ArnBuilder::service_id(service.into())
+        .in_partition_id(partition)
+        .in_region_id(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_path(resource))
+        .into()
+}
+
+fn typed_arn(
+    service: Service,
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    resource_type: &str,
+    path: &[Identifier],
+) -> ResourceName {
+    let resource: Vec<ResourceIdentifier> = std::iter::once(resource_type)
+        .map(ResourceIdentifier::new_unchecked)
+        .chain(path.iter().cloned().map(ResourceIdentifier::from))
+        .collect();
+    events_arn(service, partition, region, account, &resource)
+}
+
+fn rule_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    event_bus_name: Option<ResourceIdentifier>,
+    name: Identifier,
+) -> ResourceName {
+    let mut resource = vec![ResourceIdentifier::new_unchecked("rule")];
+    if let Some(event_bus_name) = event_bus_name.filter(|b| b.deref() != DEFAULT_EVENT_BUS) {
+        resource.push(event_bus_name);
+    }
+    resource.push(name.into());
+    events_arn(EventBridge, partition, region, account, &resource)
+}
+
+fn with_uuid(name: Identifier, uuid: Option<Identifier>) -> Vec<Identifier> {
+    std::iter::once(name).chain(uuid).collect()
+}
+
+fn check_event_bus_name(name: &ResourceIdentifier) -> Result<(), Error> {
+    check_name(name, 1..=256, |c| is_dotted_name_char(c) || c == '/')
+}
+
+fn check_uuid(uuid: &Identifier) -> Result<(), Error> {
+    check_name(uuid, 36..=36, |c| c.is_ascii_hexdigit() || c == '-')
+}
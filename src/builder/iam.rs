@@ -5,11 +5,160 @@ These resource definitions ae take from the AWS
 [documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_identityandaccessmanagement.html#identityandaccessmanagement-resources-for-iam-policies).
 With the exception  of the root account ResourceName described
 [here](https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_identifiers.html#identifiers-arns).
+
+Users, roles, groups, policies, instance profiles, server certificates, and virtual MFA devices
+may be placed under an IAM path such as `/division_abc/subdivision_xyz/`. The `{noun}_with_path`
+functions take the path as a slice of its elements, `&[division_abc, subdivision_xyz]`, and an
+empty slice represents the root path `/`; `IamResource` splits the path from the name in the
+same way.
 [*/
 
-use crate::builder::ArnBuilder;
+use crate::builder::{
+    account_of, check_name, expect_service, is_dotted_name_char, is_iam_name_char, partition_of,
+    ArnBuilder, ResourceBuilder, ServiceResource,
+};
 use crate::known::Service::IdentityAccessManagement;
-use crate::{AccountIdentifier, Identifier, IdentifierLike, ResourceIdentifier, ResourceName};
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::ops::Deref;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the IAM ResourceNames constructed by the functions in this module. For
+/// resource types that support IAM paths the path is held separately from the name.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum IamResource {
+    /// `arn:${Partition}:iam::${Account}:root`
+    Root {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+    },
+    /// `arn:${Partition}:iam::${Account}:user/${UserNameWithPath}`
+    User {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The elements of the IAM path, empty for the root path.
+        path: Vec<Identifier>,
+        /// The user name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:iam::${Account}:role/${RoleNameWithPath}`
+    Role {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The elements of the IAM path, empty for the root path.
+        path: Vec<Identifier>,
+        /// The role name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:iam::${Account}:role/aws-service-role/${ServicePrincipal}/${RoleName}`
+    ServiceLinkedRole {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The service principal that the role is linked to, for example
+        /// `elasticbeanstalk.amazonaws.com`.
+        service: Identifier,
+        /// The role name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:iam::${Account}:group/${GroupNameWithPath}`
+    Group {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The elements of the IAM path, empty for the root path.
+        path: Vec<Identifier>,
+        /// The group name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:iam::${Account}:policy/${PolicyNameWithPath}`
+    Policy {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The elements of the IAM path, empty for the root path.
+        path: Vec<Identifier>,
+        /// The policy name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:iam::${Account}:instance-profile/${InstanceProfileNameWithPath}`
+    InstanceProfile {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The elements of the IAM path, empty for the root path.
+        path: Vec<Identifier>,
+        /// The instance profile name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:iam::${Account}:server-certificate/${CertificateNameWithPath}`
+    ServerCertificate {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The elements of the IAM path, empty for the root path.
+        path: Vec<Identifier>,
+        /// The certificate name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:iam::${Account}:mfa/${MfaTokenIdWithPath}`
+    VirtualMfaDevice {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The elements of the IAM path, empty for the root path.
+        path: Vec<Identifier>,
+        /// The virtual MFA device name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:iam::${Account}:saml-provider/${SamlProviderName}`
+    SamlProvider {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The SAML provider name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:iam::${Account}:oidc-provider/${OidcProviderName}`
+    OidcProvider {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The OIDC provider URL without the scheme, which may include '/' characters.
+        url: ResourceIdentifier,
+    },
+    /// `arn:${Partition}:iam::${Account}:access-report/${EntityPath}`
+    AccessReport {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The Organizations entity path, which may include '/' characters.
+        entity_path: ResourceIdentifier,
+    },
+}
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
@@ -26,73 +175,582 @@ pub fn root(account: AccountIdentifier) -> ResourceName {
 }
 
 ///
-/// `arn:${Partition}:iam::${Account}:user/${UserNameWithPath}`
+/// `arn:${Partition}:iam::${Account}:user/${UserName}`
 ///
 pub fn user(
     partition: Identifier,
     account: AccountIdentifier,
     user_name: Identifier,
-) -> ResourceName {
-    ArnBuilder::service_id(IdentityAccessManagement.into())
-        .in_partition_id(partition)
-        .owned_by(account)
-        .is(ResourceIdentifier::from_id_path(&[
-            Identifier::new_unchecked("user"),
-            user_name,
-        ]))
-        .into()
+) -> Result<ResourceName, Error> {
+    user_with_path(partition, account, &[], user_name)
 }
 
 ///
-/// `arn:${Partition}:iam::${Account}:role/${RoleNameWithPath}`
+/// `arn:${Partition}:iam::${Account}:user/${UserNameWithPath}`
+///
+pub fn user_with_path(
+    partition: Identifier,
+    account: AccountIdentifier,
+    path: &[Identifier],
+    user_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_path(path)?;
+    check_name(&user_name, 1..=64, is_iam_name_char)?;
+    Ok(iam_arn(
+        partition,
+        account,
+        path_resource("user", path, user_name),
+    ))
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:role/${RoleName}`
 ///
 pub fn role(
     partition: Identifier,
     account: AccountIdentifier,
     role_name: Identifier,
-) -> ResourceName {
-    ArnBuilder::service_id(IdentityAccessManagement.into())
-        .in_partition_id(partition)
-        .owned_by(account)
-        .is(ResourceIdentifier::from_id_path(&[
-            Identifier::new_unchecked("role"),
+) -> Result<ResourceName, Error> {
+    role_with_path(partition, account, &[], role_name)
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:role/${RoleNameWithPath}`
+///
+pub fn role_with_path(
+    partition: Identifier,
+    account: AccountIdentifier,
+    path: &[Identifier],
+    role_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_path(path)?;
+    check_name(&role_name, 1..=64, is_iam_name_char)?;
+    Ok(iam_arn(
+        partition,
+        account,
+        path_resource("role", path, role_name),
+    ))
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:role/aws-service-role/${ServicePrincipal}/${RoleName}`
+///
+pub fn service_linked_role(
+    partition: Identifier,
+    account: AccountIdentifier,
+    service_principal: Identifier,
+    role_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&service_principal, 1..=128, |c| {
+        c.is_ascii_alphanumeric() || c == '.' || c == '-'
+    })?;
+    check_name(&role_name, 1..=64, is_iam_name_char)?;
+    Ok(iam_arn(
+        partition,
+        account,
+        path_resource(
+            "role",
+            &[
+                Identifier::new_unchecked(SERVICE_ROLE_PATH),
+                service_principal,
+            ],
             role_name,
-        ]))
-        .into()
+        ),
+    ))
 }
 
 ///
-/// `arn:${Partition}:iam::${Account}:group/${GroupNameWithPath}`
+/// `arn:${Partition}:iam::${Account}:group/${GroupName}`
 ///
 pub fn group(
     partition: Identifier,
     account: AccountIdentifier,
     group_name: Identifier,
-) -> ResourceName {
-    ArnBuilder::service_id(IdentityAccessManagement.into())
-        .in_partition_id(partition)
-        .owned_by(account)
-        .is(ResourceIdentifier::from_id_path(&[
-            Identifier::new_unchecked("group"),
-            group_name,
-        ]))
-        .into()
+) -> Result<ResourceName, Error> {
+    group_with_path(partition, account, &[], group_name)
 }
 
 ///
-/// `arn:${Partition}:iam::${Account}:policy/${PolicyNameWithPath}`
+/// `arn:${Partition}:iam::${Account}:group/${GroupNameWithPath}`
+///
+pub fn group_with_path(
+    partition: Identifier,
+    account: AccountIdentifier,
+    path: &[Identifier],
+    group_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_path(path)?;
+    check_name(&group_name, 1..=128, is_iam_name_char)?;
+    Ok(iam_arn(
+        partition,
+        account,
+        path_resource("group", path, group_name),
+    ))
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:policy/${PolicyName}`
 ///
 pub fn policy(
     partition: Identifier,
     account: AccountIdentifier,
     policy_name: Identifier,
+) -> Result<ResourceName, Error> {
+    policy_with_path(partition, account, &[], policy_name)
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:policy/${PolicyNameWithPath}`
+///
+pub fn policy_with_path(
+    partition: Identifier,
+    account: AccountIdentifier,
+    path: &[Identifier],
+    policy_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_path(path)?;
+    check_name(&policy_name, 1..=128, is_iam_name_char)?;
+    Ok(iam_arn(
+        partition,
+        account,
+        path_resource("policy", path, policy_name),
+    ))
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:instance-profile/${InstanceProfileName}`
+///
+pub fn instance_profile(
+    partition: Identifier,
+    account: AccountIdentifier,
+    instance_profile_name: Identifier,
+) -> Result<ResourceName, Error> {
+    instance_profile_with_path(partition, account, &[], instance_profile_name)
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:instance-profile/${InstanceProfileNameWithPath}`
+///
+pub fn instance_profile_with_path(
+    partition: Identifier,
+    account: AccountIdentifier,
+    path: &[Identifier],
+    instance_profile_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_path(path)?;
+    check_name(&instance_profile_name, 1..=128, is_iam_name_char)?;
+    Ok(iam_arn(
+        partition,
+        account,
+        path_resource("instance-profile", path, instance_profile_name),
+    ))
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:server-certificate/${CertificateName}`
+///
+pub fn server_certificate(
+    partition: Identifier,
+    account: AccountIdentifier,
+    certificate_name: Identifier,
+) -> Result<ResourceName, Error> {
+    server_certificate_with_path(partition, account, &[], certificate_name)
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:server-certificate/${CertificateNameWithPath}`
+///
+pub fn server_certificate_with_path(
+    partition: Identifier,
+    account: AccountIdentifier,
+    path: &[Identifier],
+    certificate_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_path(path)?;
+    check_name(&certificate_name, 1..=128, is_iam_name_char)?;
+    Ok(iam_arn(
+        partition,
+        account,
+        path_resource("server-certificate", path, certificate_name),
+    ))
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:mfa/${MfaTokenId}`
+///
+pub fn virtual_mfa_device(
+    partition: Identifier,
+    account: AccountIdentifier,
+    device_name: Identifier,
+) -> Result<ResourceName, Error> {
+    virtual_mfa_device_with_path(partition, account, &[], device_name)
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:mfa/${MfaTokenIdWithPath}`
+///
+pub fn virtual_mfa_device_with_path(
+    partition: Identifier,
+    account: AccountIdentifier,
+    path: &[Identifier],
+    device_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_path(path)?;
+    check_name(&device_name, 1..=226, is_iam_name_char)?;
+    Ok(iam_arn(
+        partition,
+        account,
+        path_resource("mfa", path, device_name),
+    ))
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:saml-provider/${SamlProviderName}`
+///
+pub fn saml_provider(
+    partition: Identifier,
+    account: AccountIdentifier,
+    provider_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&provider_name, 1..=128, is_dotted_name_char)?;
+    Ok(iam_arn(
+        partition,
+        account,
+        path_resource("saml-provider", &[], provider_name),
+    ))
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:oidc-provider/${OidcProviderName}`
+///
+/// The provider is identified by its URL without the `https://` scheme, for example
+/// `oidc.eks.us-west-2.amazonaws.com/id/EXAMPLED539D4633E53DE1B71EXAMPLE`.
+///
+pub fn oidc_provider(
+    partition: Identifier,
+    account: AccountIdentifier,
+    provider_url: ResourceIdentifier,
+) -> Result<ResourceName, Error> {
+    if provider_url.contains("://") || provider_url.len() > 255 {
+        return Err(Error::InvalidResource(provider_url.to_string()));
+    }
+    Ok(iam_arn(
+        partition,
+        account,
+        typed_path("oidc-provider", provider_url),
+    ))
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:access-report/${EntityPath}`
+///
+/// The entity path is the Organizations path to the entity the report covers, the organization
+/// ID followed by the root ID, any organizational unit IDs, and optionally an account ID; for
+/// example `o-a1b2c3d4e5/r-f6g7h8i9j0example/ou-1a2b3c-k9l8m7n6o5example`.
+///
+pub fn access_report(
+    partition: Identifier,
+    account: AccountIdentifier,
+    entity_path: ResourceIdentifier,
+) -> Result<ResourceName, Error> {
+    check_entity_path(&entity_path)?;
+    Ok(iam_arn(
+        partition,
+        account,
+        typed_path("access-report", entity_path),
+    ))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl TryFrom<&ResourceName> for IamResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        expect_service(arn, IdentityAccessManagement)?;
+        let partition = partition_of(arn);
+        let account = account_of(arn)?;
+        if arn.region.as_ref().is_some_and(|r| !r.is_empty()) {
+            return Err(Error::RegionNotAllowed);
+        }
+        if arn.resource.deref() == "root" {
+            return Ok(IamResource::Root { partition, account });
+        }
+        let invalid = || Error::InvalidResource(arn.resource.to_string());
+        let (resource_type, rest) = arn.resource.split_once('/').ok_or_else(invalid)?;
+        match resource_type {
+            "oidc-provider" => {
+                return Ok(IamResource::OidcProvider {
+                    partition,
+                    account,
+                    url: ResourceIdentifier::from_str(rest).map_err(|_| invalid())?,
+                })
+            }
+            "access-report" => {
+                return Ok(IamResource::AccessReport {
+                    partition,
+                    account,
+                    entity_path: ResourceIdentifier::from_str(rest).map_err(|_| invalid())?,
+                })
+            }
+            _ => {}
+        }
+        let mut path = rest
+            .split('/')
+            .map(Identifier::from_str)
+            .collect::<Result<Vec<Identifier>, Error>>()
+            .map_err(|_| invalid())?;
+        let name = path.pop().ok_or_else(invalid)?;
+        match (resource_type, path.as_slice()) {
+            ("role", [service_role, service]) if service_role.deref() == SERVICE_ROLE_PATH => {
+                Ok(IamResource::ServiceLinkedRole {
+                    partition,
+                    account,
+                    service: service.clone(),
+                    name,
+                })
+            }
+            ("user", _) => Ok(IamResource::User {
+                partition,
+                account,
+                path,
+                name,
+            }),
+            ("role", _) => Ok(IamResource::Role {
+                partition,
+                account,
+                path,
+                name,
+            }),
+            ("group", _) => Ok(IamResource::Group {
+                partition,
+                account,
+                path,
+                name,
+            }),
+            ("policy", _) => Ok(IamResource::Policy {
+                partition,
+                account,
+                path,
+                name,
+            }),
+            ("instance-profile", _) => Ok(IamResource::InstanceProfile {
+                partition,
+                account,
+                path,
+                name,
+            }),
+            ("server-certificate", _) => Ok(IamResource::ServerCertificate {
+                partition,
+                account,
+                path,
+                name,
+            }),
+            ("mfa", _) => Ok(IamResource::VirtualMfaDevice {
+                partition,
+                account,
+                path,
+                name,
+            }),
+            ("saml-provider", []) => Ok(IamResource::SamlProvider {
+                partition,
+                account,
+                name,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl From<&IamResource> for ResourceName {
+    fn from(v: &IamResource) -> Self {
+        let resource_type = v.resource_type_name();
+        match v.clone() {
+            IamResource::Root { partition, account } => iam_arn(
+                partition,
+                account,
+                ResourceIdentifier::new_unchecked("root"),
+            ),
+            IamResource::ServiceLinkedRole {
+                partition,
+                account,
+                service,
+                name,
+            } => iam_arn(
+                partition,
+                account,
+                path_resource(
+                    resource_type,
+                    &[Identifier::new_unchecked(SERVICE_ROLE_PATH), service],
+                    name,
+                ),
+            ),
+            IamResource::User {
+                partition,
+                account,
+                path,
+                name,
+            }
+            | IamResource::Role {
+                partition,
+                account,
+                path,
+                name,
+            }
+            | IamResource::Group {
+                partition,
+                account,
+                path,
+                name,
+            }
+            | IamResource::Policy {
+                partition,
+                account,
+                path,
+                name,
+            }
+            | IamResource::InstanceProfile {
+                partition,
+                account,
+                path,
+                name,
+            }
+            | IamResource::ServerCertificate {
+                partition,
+                account,
+                path,
+                name,
+            }
+            | IamResource::VirtualMfaDevice {
+                partition,
+                account,
+                path,
+                name,
+            } => iam_arn(
+                partition,
+                account,
+                path_resource(resource_type, &path, name),
+            ),
+            IamResource::SamlProvider {
+                partition,
+                account,
+                name,
+            } => iam_arn(partition, account, path_resource(resource_type, &[], name)),
+            IamResource::OidcProvider {
+                partition,
+                account,
+                url,
+            } => iam_arn(partition, account, typed_path(resource_type, url)),
+            IamResource::AccessReport {
+                partition,
+                account,
+                entity_path,
+            } => iam_arn(partition, account, typed_path(resource_type, entity_path)),
+        }
+    }
+}
+
+impl From<IamResource> for ResourceName {
+    fn from(v: IamResource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for IamResource {
+    fn service(&self) -> Identifier {
+        IdentityAccessManagement.into()
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            IamResource::Root { .. } => "root",
+            IamResource::User { .. } => "user",
+            IamResource::Role { .. } => "role",
+            IamResource::ServiceLinkedRole { .. } => "role",
+            IamResource::Group { .. } => "group",
+            IamResource::Policy { .. } => "policy",
+            IamResource::InstanceProfile { .. } => "instance-profile",
+            IamResource::ServerCertificate { .. } => "server-certificate",
+            IamResource::VirtualMfaDevice { .. } => "mfa",
+            IamResource::SamlProvider { .. } => "saml-provider",
+            IamResource::OidcProvider { .. } => "oidc-provider",
+            IamResource::AccessReport { .. } => "access-report",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const SERVICE_ROLE_PATH: &str = "aws-service-role";
+
+const MAX_PATH_LENGTH: usize = 512;
+
+fn iam_arn(
+    partition: Identifier,
+    account: AccountIdentifier,
+    resource: ResourceIdentifier,
 ) -> ResourceName {
     ArnBuilder::service_id(IdentityAccessManagement.into())
         .in_partition_id(partition)
         .owned_by(account)
-        .is(ResourceIdentifier::from_id_path(&[
-            Identifier::new_unchecked("policy"),
-            policy_name,
-        ]))
+        .is(resource)
         .into()
 }
+
+fn path_resource(resource_type: &str, path: &[Identifier], name: Identifier) -> ResourceIdentifier {
+    ResourceBuilder::typed(Identifier::new_unchecked(resource_type))
+        .path(path)
+        .path_segment(name.into())
+        .build()
+}
+
+fn typed_path(resource_type: &str, name: ResourceIdentifier) -> ResourceIdentifier {
+    ResourceIdentifier::from_path(&[ResourceIdentifier::new_unchecked(resource_type), name])
+}
+
+fn check_entity_path(entity_path: &ResourceIdentifier) -> Result<(), Error> {
+    let is_entity_id = |s: &str, prefix: &str| {
+        s.strip_prefix(prefix).is_some_and(|id| {
+            !id.is_empty()
+                && id
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        })
+    };
+    let is_account_id = |s: &str| s.len() == 12 && s.chars().all(|c| c.is_ascii_digit());
+    let segments: Vec<&str> = entity_path.split('/').collect();
+    let last = segments.len() - 1;
+    let is_valid = segments.iter().enumerate().all(|(i, segment)| match i {
+        _ if segment.contains('*') || segment.contains('?') => true,
+        0 => is_entity_id(segment, "o-"),
+        1 => is_entity_id(segment, "r-"),
+        _ => is_entity_id(segment, "ou-") || (i == last && is_account_id(segment)),
+    });
+    if is_valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidResource(entity_path.to_string()))
+    }
+}
+
+fn check_path(path: &[Identifier]) -> Result<(), Error> {
+    // The full path has a leading '/' and a trailing '/' after each element.
+    let length = path.iter().map(|p| p.len() + 1).sum::<usize>() + 1;
+    if length > MAX_PATH_LENGTH {
+        let path: Vec<&str> = path.iter().map(|p| p.deref()).collect();
+        return Err(Error::InvalidIdentifier(path.join("/")));
+    }
+    path.iter().try_for_each(|element| {
+        check_name(element, 1..=MAX_PATH_LENGTH - 2, |c| {
+            ('!'..='~').contains(&c)
+        })
+    })
+}
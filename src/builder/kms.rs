@@ -0,0 +1,279 @@
+/*!
+Provides a set of simple helper functions to make ResourceNames for the KMS service.
+
+These resource definitions ae take from the AWS
+[documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_awskeymanagementservice.html#awskeymanagementservice-resources-for-iam-policies).
+
+A key ID is either a UUID, or for a multi-region key `mrk-` followed by 32 hex digits. The KMS API
+also accepts an alias name, or either form of ResourceName, in place of a key ID, and
+`from_key_id` will complete these.
+*/
+
+use crate::builder::{
+    account_of, check_name, expect_service, partition_of, region_of, ArnBuilder, ServiceResource,
+};
+use crate::known::Service::KeyManagement;
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the KMS ResourceNames constructed by the functions in this module.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum KmsResource {
+    /// `arn:${Partition}:kms:${Region}:${Account}:key/${KeyId}`
+    Key {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The key ID, a UUID or `mrk-` followed by 32 hex digits.
+        key_id: Identifier,
+    },
+    /// `arn:${Partition}:kms:${Region}:${Account}:alias/${AliasName}`
+    Alias {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The alias name, without the `alias/` prefix.
+        name: ResourceIdentifier,
+    },
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// `arn:${Partition}:kms:${Region}:${Account}:key/${KeyId}`
+///
+pub fn key(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    key_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_key_id(&key_id)?;
+    Ok(key_arn(partition, region, account, key_id))
+}
+
+///
+/// `arn:${Partition}:kms:${Region}:${Account}:alias/${AliasName}`
+///
+/// The alias name, without the `alias/` prefix, may contain alphanumeric, `/`, `_`, or `-`
+/// characters.
+///
+pub fn alias(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: ResourceIdentifier,
+) -> Result<ResourceName, Error> {
+    check_alias_name(&name)?;
+    Ok(alias_arn(partition, region, account, name))
+}
+
+///
+/// `arn:${Partition}:kms:${Region}:${Account}:key/${KeyId}` or
+/// `arn:${Partition}:kms:${Region}:${Account}:alias/${AliasName}`
+///
+/// Complete any of the forms of key identifier accepted by the KMS API, using the provided
+/// partition, region, and account where they are not included:
+///
+/// * a key ID, `1234abcd-12ab-34cd-56ef-1234567890ab` or `mrk-1234abcd12ab34cd56ef1234567890ab`,
+/// * an alias name, `alias/ExampleAlias`,
+/// * a key or alias ResourceName, whose key ID or alias name is checked as for the other forms.
+///
+pub fn from_key_id(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    key_id: &str,
+) -> Result<ResourceName, Error> {
+    if key_id.starts_with("arn:") {
+        let arn = ResourceName::from_str(key_id)?;
+        return match KmsResource::try_from(&arn)? {
+            KmsResource::Key {
+                partition,
+                region,
+                account,
+                key_id,
+            } => key(partition, region, account, key_id),
+            KmsResource::Alias {
+                partition,
+                region,
+                account,
+                name,
+            } => alias(partition, region, account, name),
+        };
+    }
+    match key_id.strip_prefix(ALIAS_PREFIX) {
+        Some(name) => alias(
+            partition,
+            region,
+            account,
+            ResourceIdentifier::from_str(name)?,
+        ),
+        None => key(partition, region, account, Identifier::from_str(key_id)?),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const ALIAS_PREFIX: &str = "alias/";
+
+const MULTI_REGION_PREFIX: &str = "mrk-";
+
+impl KmsResource {
+    /// Returns `true` if this is a multi-region key, that is its key ID starts with `mrk-`. The
+    /// primary key and its replicas share the same key ID, in different regions.
+    pub fn is_multi_region(&self) -> bool {
+        match self {
+            KmsResource::Key { key_id, .. } => key_id.starts_with(MULTI_REGION_PREFIX),
+            KmsResource::Alias { .. } => false,
+        }
+    }
+}
+
+impl TryFrom<&ResourceName> for KmsResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        expect_service(arn, KeyManagement)?;
+        let partition = partition_of(arn);
+        let region = region_of(arn)?;
+        let account = account_of(arn)?;
+        let invalid = || Error::InvalidResource(arn.resource.to_string());
+        match arn.resource.split_once('/') {
+            Some(("key", key_id)) => Ok(KmsResource::Key {
+                partition,
+                region,
+                account,
+                key_id: Identifier::from_str(key_id).map_err(|_| invalid())?,
+            }),
+            Some(("alias", name)) if !name.is_empty() => Ok(KmsResource::Alias {
+                partition,
+                region,
+                account,
+                name: ResourceIdentifier::from_str(name).map_err(|_| invalid())?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl From<&KmsResource> for ResourceName {
+    fn from(v: &KmsResource) -> Self {
+        match v.clone() {
+            KmsResource::Key {
+                partition,
+                region,
+                account,
+                key_id,
+            } => key_arn(partition, region, account, key_id),
+            KmsResource::Alias {
+                partition,
+                region,
+                account,
+                name,
+            } => alias_arn(partition, region, account, name),
+        }
+    }
+}
+
+impl From<KmsResource> for ResourceName {
+    fn from(v: KmsResource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for KmsResource {
+    fn service(&self) -> Identifier {
+        KeyManagement.into()
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            KmsResource::Key { .. } => "key",
+            KmsResource::Alias { .. } => "alias",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn kms_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    resource: &[ResourceIdentifier],
+) -> ResourceName {
+    ArnBuilder::service_id(KeyManagement.into())
+        .in_partition_id(partition)
+        .in_region_id(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_path(resource))
+        .into()
+}
+
+fn key_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    key_id: Identifier,
+) -> ResourceName {
+    kms_arn(
+        partition,
+        region,
+        account,
+        &[ResourceIdentifier::new_unchecked("key"), key_id.into()],
+    )
+}
+
+fn alias_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: ResourceIdentifier,
+) -> ResourceName {
+    kms_arn(
+        partition,
+        region,
+        account,
+        &[ResourceIdentifier::new_unchecked("alias"), name],
+    )
+}
+
+fn check_key_id(key_id: &Identifier) -> Result<(), Error> {
+    match key_id.strip_prefix(MULTI_REGION_PREFIX) {
+        Some(hex) => check_name(hex, 32..=32, |c| c.is_ascii_hexdigit())
+            .map_err(|_| Error::InvalidIdentifier(key_id.to_string())),
+        None => check_name(key_id, 36..=36, |c| c.is_ascii_hexdigit() || c == '-'),
+    }
+}
+
+fn check_alias_name(name: &ResourceIdentifier) -> Result<(), Error> {
+    check_name(name, 1..=(256 - ALIAS_PREFIX.len()), |c| {
+        c.is_ascii_alphanumeric() || "/_-".contains(c)
+    })
+}
@@ -3,11 +3,97 @@ Provides a set of simple helper functions to make ResourceNames for the Lambda s
 
 These resource definitions ae take from the AWS
 [documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_awslambda.html#awslambda-resources-for-iam-policies).
+
+A function ResourceName may be qualified with a version or an alias, represented by
+`LambdaQualifier`. The Lambda API also accepts a function name, or a partial ResourceName of the
+form `${Account}:function:${FunctionName}`, in place of the full ResourceName and
+`function_from_partial` will complete these.
 */
 
-use crate::builder::ArnBuilder;
+use crate::builder::{
+    account_of, check_name, expect_service, is_name_char, partition_of, region_of, ArnBuilder,
+    ServiceResource,
+};
 use crate::known::Service::Lambda;
-use crate::{AccountIdentifier, Identifier, IdentifierLike, ResourceIdentifier, ResourceName};
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the Lambda ResourceNames constructed by the functions in this module.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum LambdaResource {
+    /// `arn:${Partition}:lambda:${Region}:${Account}:function:${FunctionName}[:${Qualifier}]`
+    Function {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The function name.
+        name: Identifier,
+        /// The optional version or alias qualifier.
+        qualifier: Option<LambdaQualifier>,
+    },
+    /// `arn:${Partition}:lambda:${Region}:${Account}:layer:${LayerName}`
+    Layer {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The layer name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:lambda:${Region}:${Account}:layer:${LayerName}:${LayerVersion}`
+    LayerVersion {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The layer name.
+        name: Identifier,
+        /// The layer version, starting at 1.
+        version: u32,
+    },
+    /// `arn:${Partition}:lambda:${Region}:${Account}:event-source-mapping:${UUID}`
+    EventSourceMapping {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The event source mapping UUID.
+        uuid: Identifier,
+    },
+}
+
+///
+/// The qualifier of a function ResourceName, which selects a published version of the function, or
+/// an alias that refers to one.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LambdaQualifier {
+    /// `$LATEST`, the unpublished version of the function.
+    Latest,
+    /// A published version number, starting at 1.
+    Version(u32),
+    /// An alias name, which may not be entirely numeric.
+    Alias(Identifier),
+}
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
@@ -21,16 +107,119 @@ pub fn function(
     region: Identifier,
     account: AccountIdentifier,
     function_name: Identifier,
-) -> ResourceName {
-    ArnBuilder::service_id(Lambda.into())
-        .in_partition_id(partition)
-        .in_region_id(region)
-        .owned_by(account)
-        .is(ResourceIdentifier::from_qualified_id(&[
+) -> Result<ResourceName, Error> {
+    check_function_name(&function_name)?;
+    Ok(lambda_arn(
+        partition,
+        region,
+        account,
+        &[Identifier::new_unchecked("function"), function_name],
+    ))
+}
+
+///
+/// `arn:${Partition}:lambda:${Region}:${Account}:function:${FunctionName}:${Qualifier}`
+///
+pub fn qualified_function(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    function_name: Identifier,
+    qualifier: LambdaQualifier,
+) -> Result<ResourceName, Error> {
+    check_function_name(&function_name)?;
+    Ok(lambda_arn(
+        partition,
+        region,
+        account,
+        &[
             Identifier::new_unchecked("function"),
             function_name,
-        ]))
-        .into()
+            qualifier.into(),
+        ],
+    ))
+}
+
+///
+/// `arn:${Partition}:lambda:${Region}:${Account}:function:${FunctionName}`
+///
+/// Remove any qualifier from the function ResourceName `function`, returns
+/// `Error::ResourceMismatch` if it is not a Lambda function ResourceName.
+///
+pub fn unqualified_function_from(function: &ResourceName) -> Result<ResourceName, Error> {
+    match LambdaResource::try_from(function)? {
+        LambdaResource::Function {
+            partition,
+            region,
+            account,
+            name,
+            ..
+        } => Ok(lambda_arn(
+            partition,
+            region,
+            account,
+            &[Identifier::new_unchecked("function"), name],
+        )),
+        other => Err(Error::ResourceMismatch {
+            expected: "function".to_string(),
+            found: other.resource_type_name().to_string(),
+        }),
+    }
+}
+
+///
+/// `arn:${Partition}:lambda:${Region}:${Account}:function:${FunctionName}[:${Qualifier}]`
+///
+/// Complete any of the forms of function name accepted by the Lambda API, using the provided
+/// partition, region, and account where they are not included:
+///
+/// * a function name, `my-function`, or qualified name, `my-function:PROD`,
+/// * a partial ResourceName, `function:my-function[:PROD]` or
+///   `123456789012:function:my-function[:PROD]`,
+/// * a full ResourceName, which is only checked to be a Lambda function.
+///
+/// As a result a two-part name whose first part is `function` is always read as the partial
+/// form, never as a function named `function` with a qualifier.
+///
+pub fn function_from_partial(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    function_name: &str,
+) -> Result<ResourceName, Error> {
+    if function_name.starts_with("arn:") {
+        let arn = ResourceName::from_str(function_name)?;
+        return match LambdaResource::try_from(&arn)? {
+            function @ LambdaResource::Function { .. } => Ok(function.into()),
+            other => Err(Error::ResourceMismatch {
+                expected: "function".to_string(),
+                found: other.resource_type_name().to_string(),
+            }),
+        };
+    }
+    let parts: Vec<&str> = function_name.split(':').collect();
+    let (account, name, qualifier) = match parts.as_slice() {
+        [name] => (account, name, None),
+        [FUNCTION, name] => (account, name, None),
+        [FUNCTION, name, qualifier] => (account, name, Some(qualifier)),
+        [name, qualifier] => (account, name, Some(qualifier)),
+        [account, FUNCTION, name] => (AccountIdentifier::from_str(account)?, name, None),
+        [account, FUNCTION, name, qualifier] => {
+            (AccountIdentifier::from_str(account)?, name, Some(qualifier))
+        }
+        _ => return Err(Error::InvalidResource(function_name.to_string())),
+    };
+    let name = Identifier::from_str(name)?;
+    match qualifier {
+        None => function(partition, region, account, name),
+        Some(qualifier) => qualified_function(
+            partition,
+            region,
+            account,
+            name,
+            LambdaQualifier::from_str(qualifier)?,
+        ),
+    }
 }
 
 ///
@@ -41,16 +230,14 @@ pub fn layer(
     region: Identifier,
     account: AccountIdentifier,
     layer_name: Identifier,
-) -> ResourceName {
-    ArnBuilder::service_id(Lambda.into())
-        .in_partition_id(partition)
-        .in_region_id(region)
-        .owned_by(account)
-        .is(ResourceIdentifier::from_qualified_id(&[
-            Identifier::new_unchecked("layer"),
-            layer_name,
-        ]))
-        .into()
+) -> Result<ResourceName, Error> {
+    check_layer_name(&layer_name)?;
+    Ok(lambda_arn(
+        partition,
+        region,
+        account,
+        &[Identifier::new_unchecked("layer"), layer_name],
+    ))
 }
 
 ///
@@ -61,18 +248,22 @@ pub fn layer_version(
     region: Identifier,
     account: AccountIdentifier,
     layer_name: Identifier,
-    layer_version: i32,
-) -> ResourceName {
-    ArnBuilder::service_id(Lambda.into())
-        .in_partition_id(partition)
-        .in_region_id(region)
-        .owned_by(account)
-        .is(ResourceIdentifier::from_qualified_id(&[
+    layer_version: u32,
+) -> Result<ResourceName, Error> {
+    check_layer_name(&layer_name)?;
+    if layer_version == 0 {
+        return Err(Error::InvalidIdentifier(layer_version.to_string()));
+    }
+    Ok(lambda_arn(
+        partition,
+        region,
+        account,
+        &[
             Identifier::new_unchecked("layer"),
             layer_name,
             Identifier::new_unchecked(&layer_version.to_string()),
-        ]))
-        .into()
+        ],
+    ))
 }
 
 ///
@@ -83,14 +274,229 @@ pub fn event_source_mapping(
     region: Identifier,
     account: AccountIdentifier,
     mapping_uuid: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&mapping_uuid, 36..=36, |c| {
+        c.is_ascii_hexdigit() || c == '-'
+    })?;
+    Ok(lambda_arn(
+        partition,
+        region,
+        account,
+        &[
+            Identifier::new_unchecked("event-source-mapping"),
+            mapping_uuid,
+        ],
+    ))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl TryFrom<&ResourceName> for LambdaResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        expect_service(arn, Lambda)?;
+        let partition = partition_of(arn);
+        let region = region_of(arn)?;
+        let account = account_of(arn)?;
+        let invalid = || Error::InvalidResource(arn.resource.to_string());
+        let parts: Vec<&str> = arn.resource.split(':').collect();
+        match parts.as_slice() {
+            ["function", name] => Ok(LambdaResource::Function {
+                partition,
+                region,
+                account,
+                name: Identifier::from_str(name)?,
+                qualifier: None,
+            }),
+            ["function", name, qualifier] => Ok(LambdaResource::Function {
+                partition,
+                region,
+                account,
+                name: Identifier::from_str(name)?,
+                qualifier: Some(LambdaQualifier::from_str(qualifier).map_err(|_| invalid())?),
+            }),
+            ["layer", name] => Ok(LambdaResource::Layer {
+                partition,
+                region,
+                account,
+                name: Identifier::from_str(name)?,
+            }),
+            ["layer", name, version] => Ok(LambdaResource::LayerVersion {
+                partition,
+                region,
+                account,
+                name: Identifier::from_str(name)?,
+                version: parse_version(version).ok_or_else(invalid)?,
+            }),
+            ["event-source-mapping", uuid] => Ok(LambdaResource::EventSourceMapping {
+                partition,
+                region,
+                account,
+                uuid: Identifier::from_str(uuid)?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl From<&LambdaResource> for ResourceName {
+    fn from(v: &LambdaResource) -> Self {
+        match v.clone() {
+            LambdaResource::Function {
+                partition,
+                region,
+                account,
+                name,
+                qualifier,
+            } => {
+                let mut resource = vec![Identifier::new_unchecked("function"), name];
+                resource.extend(qualifier.map(Identifier::from));
+                lambda_arn(partition, region, account, &resource)
+            }
+            LambdaResource::Layer {
+                partition,
+                region,
+                account,
+                name,
+            } => lambda_arn(
+                partition,
+                region,
+                account,
+                &[Identifier::new_unchecked("layer"), name],
+            ),
+            LambdaResource::LayerVersion {
+                partition,
+                region,
+                account,
+                name,
+                version,
+            } => lambda_arn(
+                partition,
+                region,
+                account,
+                &[
+                    Identifier::new_unchecked("layer"),
+                    name,
+                    Identifier::new_unchecked(&version.to_string()),
+                ],
+            ),
+            LambdaResource::EventSourceMapping {
+                partition,
+                region,
+                account,
+                uuid,
+            } => lambda_arn(
+                partition,
+                region,
+                account,
+                &[Identifier::new_unchecked("event-source-mapping"), uuid],
+            ),
+        }
+    }
+}
+
+impl From<LambdaResource> for ResourceName {
+    fn from(v: LambdaResource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for LambdaResource {
+    fn service(&self) -> Identifier {
+        Lambda.into()
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            LambdaResource::Function { .. } => "function",
+            LambdaResource::Layer { .. } => "layer",
+            LambdaResource::LayerVersion { .. } => "layer",
+            LambdaResource::EventSourceMapping { .. } => "event-source-mapping",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for LambdaQualifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LambdaQualifier::Latest => write!(f, "{}", QUALIFIER_LATEST),
+            LambdaQualifier::Version(version) => write!(f, "{}", version),
+            LambdaQualifier::Alias(alias) => write!(f, "{}", alias),
+        }
+    }
+}
+
+impl FromStr for LambdaQualifier {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == QUALIFIER_LATEST {
+            Ok(LambdaQualifier::Latest)
+        } else if !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) {
+            parse_version(s)
+                .map(LambdaQualifier::Version)
+                .ok_or_else(|| Error::InvalidIdentifier(s.to_string()))
+        } else {
+            let alias = Identifier::from_str(s)?;
+            check_name(&alias, 1..=128, is_name_char)?;
+            Ok(LambdaQualifier::Alias(alias))
+        }
+    }
+}
+
+impl From<LambdaQualifier> for Identifier {
+    fn from(v: LambdaQualifier) -> Self {
+        match v {
+            LambdaQualifier::Alias(alias) => alias,
+            _ => Identifier::new_unchecked(&v.to_string()),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const FUNCTION: &str = "function";
+
+const QUALIFIER_LATEST: &str = "$LATEST";
+
+fn lambda_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    resource: &[Identifier],
 ) -> ResourceName {
     ArnBuilder::service_id(Lambda.into())
         .in_partition_id(partition)
         .in_region_id(region)
         .owned_by(account)
-        .is(ResourceIdentifier::from_qualified_id(&[
-            Identifier::new_unchecked("event-source-mapping"),
-            mapping_uuid,
-        ]))
+        .is(ResourceIdentifier::from_qualified_id(resource))
         .into()
 }
+
+// Versions are numbered from 1 and written without leading zeros, so that they round-trip.
+fn parse_version(s: &str) -> Option<u32> {
+    if s.starts_with('0') || !s.chars().all(|c| c.is_ascii_digit()) {
+        None
+    } else {
+        s.parse().ok()
+    }
+}
+
+fn check_function_name(name: &Identifier) -> Result<(), Error> {
+    check_name(name, 1..=64, is_name_char)
+}
+
+fn check_layer_name(name: &Identifier) -> Result<(), Error> {
+    check_name(name, 1..=140, is_name_char)
+}
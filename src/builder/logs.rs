@@ -0,0 +1,393 @@
+/*!
+Provides a set of simple helper functions to make ResourceNames for the CloudWatch Logs service.
+
+These resource definitions ae take from the AWS
+[documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazoncloudwatchlogs.html#amazoncloudwatchlogs-resources-for-iam-policies).
+
+Some CloudWatch Logs APIs return log group ResourceNames with a trailing `:*`, which matches all of
+the streams in the group, and some return them without it. `LogsResource` accepts both forms, and
+the two compare equal, but it records which was parsed so that it produces the same form again;
+`log_group_from` always produces the form without the suffix, and `log_group_pattern` produces the
+suffixed form.
+*/
+
+use crate::builder::lambda::LambdaResource;
+use crate::builder::{
+    account_of, check_name, expect_service, partition_of, region_of, ArnBuilder, ServiceResource,
+};
+use crate::known::Service::CloudWatchLogs;
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the CloudWatch Logs ResourceNames constructed by the functions in this
+/// module.
+///
+#[derive(Clone, Debug)]
+pub enum LogsResource {
+    /// `arn:${Partition}:logs:${Region}:${Account}:log-group:${LogGroupName}[:*]`
+    LogGroup {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The log group name, which may include `/` characters.
+        name: ResourceIdentifier,
+        /// `true` if the ResourceName has the trailing `:*` that matches all of the streams; this
+        /// only affects the ResourceName produced, and is ignored when comparing log groups.
+        any_stream: bool,
+    },
+    /// `arn:${Partition}:logs:${Region}:${Account}:log-group:${LogGroupName}:log-stream:${LogStreamName}`
+    LogStream {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The name of the log group containing the stream.
+        log_group_name: ResourceIdentifier,
+        /// The log stream name.
+        name: ResourceIdentifier,
+    },
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// `arn:${Partition}:logs:${Region}:${Account}:log-group:${LogGroupName}`
+///
+pub fn log_group(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: ResourceIdentifier,
+) -> Result<ResourceName, Error> {
+    check_log_group_name(&name)?;
+    Ok(log_group_arn(partition, region, account, name))
+}
+
+///
+/// `arn:${Partition}:logs:${Region}:${Account}:log-group:${LogGroupName}:*`
+///
+/// A pattern, for use in IAM policies, that matches the log group and all of its streams.
+///
+pub fn log_group_pattern(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: ResourceIdentifier,
+) -> Result<ResourceName, Error> {
+    check_log_group_name(&name)?;
+    Ok(log_group_pattern_arn(partition, region, account, name))
+}
+
+///
+/// `arn:${Partition}:logs:${Region}:${Account}:log-group:${LogGroupName}:log-stream:${LogStreamName}`
+///
+pub fn log_stream(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    log_group_name: ResourceIdentifier,
+    name: ResourceIdentifier,
+) -> Result<ResourceName, Error> {
+    check_log_group_name(&log_group_name)?;
+    check_name(&name, 1..=512, |c| c != ':' && c != '*')?;
+    Ok(log_stream_arn(
+        partition,
+        region,
+        account,
+        log_group_name,
+        name,
+    ))
+}
+
+///
+/// Return the log group ResourceName, without the `:*` suffix, for a log group ResourceName in
+/// either form, or for a log stream ResourceName.
+///
+pub fn log_group_from(arn: &ResourceName) -> Result<ResourceName, Error> {
+    match LogsResource::try_from(arn)? {
+        LogsResource::LogGroup {
+            partition,
+            region,
+            account,
+            name,
+            ..
+        }
+        | LogsResource::LogStream {
+            partition,
+            region,
+            account,
+            log_group_name: name,
+            ..
+        } => Ok(log_group_arn(partition, region, account, name)),
+    }
+}
+
+///
+/// `arn:${Partition}:logs:${Region}:${Account}:log-group:/aws/lambda/${FunctionName}`
+///
+/// Return the log group that Lambda writes to for a function ResourceName, in the same partition,
+/// region, and account; any qualifier on the function is ignored.
+///
+pub fn function_log_group_from(function: &ResourceName) -> Result<ResourceName, Error> {
+    match LambdaResource::try_from(function)? {
+        LambdaResource::Function {
+            partition,
+            region,
+            account,
+            name,
+            ..
+        } => Ok(log_group_arn(
+            partition,
+            region,
+            account,
+            ResourceIdentifier::new_unchecked(&format!("{}{}", LAMBDA_LOG_GROUP_PREFIX, name)),
+        )),
+        other => Err(Error::ResourceMismatch {
+            expected: "function".to_string(),
+            found: other.resource_type_name().to_string(),
+        }),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const LOG_GROUP: &str = "log-group";
+
+const LOG_STREAM: &str = "log-stream";
+
+const ANY_STREAM: &str = "*";
+
+const LAMBDA_LOG_GROUP_PREFIX: &str = "/aws/lambda/";
+
+impl TryFrom<&ResourceName> for LogsResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        expect_service(arn, CloudWatchLogs)?;
+        let partition = partition_of(arn);
+        let region = region_of(arn)?;
+        let account = account_of(arn)?;
+        let invalid = || Error::InvalidResource(arn.resource.to_string());
+        let name = |s: &str| ResourceIdentifier::from_str(s).map_err(|_| invalid());
+        let parts: Vec<&str> = arn.resource.split(':').collect();
+        match parts.as_slice() {
+            [LOG_GROUP, group] => Ok(LogsResource::LogGroup {
+                partition,
+                region,
+                account,
+                name: name(group)?,
+                any_stream: false,
+            }),
+            [LOG_GROUP, group, ANY_STREAM] => Ok(LogsResource::LogGroup {
+                partition,
+                region,
+                account,
+                name: name(group)?,
+                any_stream: true,
+            }),
+            [LOG_GROUP, group, LOG_STREAM, stream] => Ok(LogsResource::LogStream {
+                partition,
+                region,
+                account,
+                log_group_name: name(group)?,
+                name: name(stream)?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl PartialEq for LogsResource {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                LogsResource::LogGroup {
+                    partition,
+                    region,
+                    account,
+                    name,
+                    ..
+                },
+                LogsResource::LogGroup {
+                    partition: other_partition,
+                    region: other_region,
+                    account: other_account,
+                    name: other_name,
+                    ..
+                },
+            ) => {
+                partition == other_partition
+                    && region == other_region
+                    && account == other_account
+                    && name == other_name
+            }
+            (
+                LogsResource::LogStream {
+                    partition,
+                    region,
+                    account,
+                    log_group_name,
+                    name,
+                },
+                LogsResource::LogStream {
+                    partition: other_partition,
+                    region: other_region,
+                    account: other_account,
+                    log_group_name: other_log_group_name,
+                    name: other_name,
+                },
+            ) => {
+                partition == other_partition
+                    && region == other_region
+                    && account == other_account
+                    && log_group_name == other_log_group_name
+                    && name == other_name
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<&LogsResource> for ResourceName {
+    fn from(v: &LogsResource) -> Self {
+        match v.clone() {
+            LogsResource::LogGroup {
+                partition,
+                region,
+                account,
+                name,
+                any_stream: false,
+            } => log_group_arn(partition, region, account, name),
+            LogsResource::LogGroup {
+                partition,
+                region,
+                account,
+                name,
+                any_stream: true,
+            } => log_group_pattern_arn(partition, region, account, name),
+            LogsResource::LogStream {
+                partition,
+                region,
+                account,
+                log_group_name,
+                name,
+            } => log_stream_arn(partition, region, account, log_group_name, name),
+        }
+    }
+}
+
+impl From<LogsResource> for ResourceName {
+    fn from(v: LogsResource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for LogsResource {
+    fn service(&self) -> Identifier {
+        CloudWatchLogs.into()
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            LogsResource::LogGroup { .. } => LOG_GROUP,
+            LogsResource::LogStream { .. } => LOG_STREAM,
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn logs_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    resource: &[ResourceIdentifier],
+) -> ResourceName {
+    ArnBuilder::service_id(CloudWatchLogs.into())
+        .in_partition_id(partition)
+        .in_region_id(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_qualified(resource))
+        .into()
+}
+
+fn log_group_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: ResourceIdentifier,
+) -> ResourceName {
+    logs_arn(
+        partition,
+        region,
+        account,
+        &[ResourceIdentifier::new_unchecked(LOG_GROUP), name],
+    )
+}
+
+fn log_group_pattern_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: ResourceIdentifier,
+) -> ResourceName {
+    logs_arn(
+        partition,
+        region,
+        account,
+        &[
+            ResourceIdentifier::new_unchecked(LOG_GROUP),
+            name,
+            ResourceIdentifier::new_unchecked(ANY_STREAM),
+        ],
+    )
+}
+
+fn log_stream_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    log_group_name: ResourceIdentifier,
+    name: ResourceIdentifier,
+) -> ResourceName {
+    logs_arn(
+        partition,
+        region,
+        account,
+        &[
+            ResourceIdentifier::new_unchecked(LOG_GROUP),
+            log_group_name,
+            ResourceIdentifier::new_unchecked(LOG_STREAM),
+            name,
+        ],
+    )
+}
+
+fn check_log_group_name(name: &ResourceIdentifier) -> Result<(), Error> {
+    check_name(name, 1..=512, |c| {
+        c.is_ascii_alphanumeric() || "_-/.#".contains(c)
+    })
+}
@@ -18,9 +18,30 @@
 * constructed from an existing bucket ResourceName, additional `{noun}_from(other,...)` functions will
 * be provided.
 *
+* The service builder functions check their `Identifier` arguments against the service's naming
+* rules and so return `Result<ResourceName, Error>`; a `{noun}_from` function returns
+* `Error::ResourceMismatch` if the other ResourceName is for the wrong service or resource type.
+*
+* Each service module also provides a typed resource enum, for example `lambda::LambdaResource`,
+* that implements `TryFrom<&ResourceName>` to recover the resource type and its components from an
+* existing ResourceName, and which converts back into a `ResourceName` with `From`. The
+* `KnownResource` enum dispatches to these on the service, and all of them implement the
+* `ServiceResource` trait.
+*
 * Note that the final `build()` function will call `validate()`, and so it is possible to call
 * intermediate functions with bad data which is only caught at build time.
 *
+* To edit an existing ResourceName, an `ArnBuilder` can be constructed from it with `From`, for
+* example `ArnBuilder::from(&arn).in_region(Region::UsWest2)`. Alternatively the `with_partition`,
+* `with_region`, `with_account`, and `with_resource` methods on `ResourceName` return a validated
+* copy with the one component replaced.
+*
+* # Typestate Builder
+*
+* The `typed` module provides a variant of `ArnBuilder` that uses per-service marker types to
+* ensure, at compile time, that the region and account components are provided when the
+* service requires them, and are not provided when the service does not allow them.
+*
 * # Example
 *
 * The following shows the construction of an AWS versioned layer ResourceName.
@@ -50,7 +71,12 @@
 */
 
 use crate::known::{Partition, Region, Service};
-use crate::{AccountIdentifier, Identifier, IdentifierLike, ResourceIdentifier, ResourceName};
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -68,11 +94,83 @@ pub struct ArnBuilder {
 /// Builder type for a `ResourceIdentifier`.
 ///
 /// The methods `build_resource_path` and `build_qualified_id` are used to construct identifiers
-/// with either the '/' or ':' separator between the collected components.
+/// with either the '/' or ':' separator between the collected components. Alternatively, the
+/// methods `segment`, `path_segment`, `qualifier_segment`, and `path` record the separator that
+/// precedes each component and `build` will then use these to construct identifiers such as
+/// `function:name:alias` or `targetgroup/name/id` that mix separators.
 ///
 #[derive(Clone, Debug, Default)]
 pub struct ResourceBuilder {
-    resource: Vec<ResourceIdentifier>,
+    resource: Vec<(Option<Separator>, ResourceIdentifier)>,
+}
+
+///
+/// The separator that precedes a component added to a `ResourceBuilder`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Separator {
+    /// The path separator, '/'.
+    #[default]
+    Path,
+    /// The qualifier separator, ':'.
+    Qualifier,
+}
+
+///
+/// Implemented by the typed resource enums in the service modules, and by `KnownResource`, so that
+/// heterogeneous lists of resources may be handled generically.
+///
+pub trait ServiceResource {
+    /// The service identifier for this resource.
+    fn service(&self) -> Identifier;
+
+    /// The name of the resource type, as used in the AWS documentation, for example `function`
+    /// or `bucket`.
+    fn resource_type_name(&self) -> &str;
+
+    /// Return the ResourceName for this resource.
+    fn to_arn(&self) -> ResourceName;
+}
+
+///
+/// A single entry point for the typed resource enums in the service modules. Converting from a
+/// ResourceName dispatches on the service; ResourceNames for services that are not supported by a
+/// service module, and any that the service module fails to parse, such as an unsupported resource
+/// type or a missing region, are returned as `Unknown`. The conversion therefore never fails, so
+/// that inventory and audit tools can classify every ResourceName they find.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum KnownResource {
+    /// A Cognito Identity, Cognito user pool, or Cognito Sync resource.
+    Cognito(cognito::CognitoResource),
+    /// A DynamoDB resource.
+    DynamoDb(dynamodb::DynamoDbResource),
+    /// An EC2 resource.
+    Ec2(ec2::Ec2Resource),
+    /// An EventBridge, EventBridge Pipes, or EventBridge Scheduler resource.
+    Events(events::EventsResource),
+    /// An IAM resource.
+    Iam(iam::IamResource),
+    /// A KMS resource.
+    Kms(kms::KmsResource),
+    /// A Lambda resource.
+    Lambda(lambda::LambdaResource),
+    /// A CloudWatch Logs resource.
+    Logs(logs::LogsResource),
+    /// An S3, S3 Object Lambda, S3 on Outposts, or S3 Express resource.
+    S3(s3::S3Resource),
+    /// A Secrets Manager resource.
+    SecretsManager(secretsmanager::SecretsManagerResource),
+    /// An SNS resource.
+    Sns(sns::SnsResource),
+    /// An SQS resource.
+    Sqs(sqs::SqsResource),
+    /// An SSM resource.
+    Ssm(ssm::SsmResource),
+    /// An STS resource.
+    Sts(sts::StsResource),
+    /// Any other resource.
+    Unknown(ResourceName),
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -91,6 +189,18 @@ impl From<&mut ArnBuilder> for ResourceName {
     }
 }
 
+impl From<ResourceName> for ArnBuilder {
+    fn from(arn: ResourceName) -> Self {
+        Self { arn }
+    }
+}
+
+impl From<&ResourceName> for ArnBuilder {
+    fn from(arn: &ResourceName) -> Self {
+        Self { arn: arn.clone() }
+    }
+}
+
 impl ArnBuilder {
     /// Construct an ResourceName for the specified `service`.
     pub fn service(service: Service) -> Self {
@@ -212,14 +322,16 @@ impl ArnBuilder {
 
 impl From<ResourceIdentifier> for ResourceBuilder {
     fn from(v: ResourceIdentifier) -> Self {
-        Self { resource: vec![v] }
+        Self {
+            resource: vec![(None, v)],
+        }
     }
 }
 
 impl From<Identifier> for ResourceBuilder {
     fn from(v: Identifier) -> Self {
         Self {
-            resource: vec![v.into()],
+            resource: vec![(None, v.into())],
         }
     }
 }
@@ -228,68 +340,365 @@ impl ResourceBuilder {
     /// Construct a new resource builder containing only the provided identifier.
     pub fn named(id: Identifier) -> Self {
         Self {
-            resource: vec![id.into()],
+            resource: vec![(None, id.into())],
         }
     }
 
     /// Construct a new resource builder containing only the provided identifier.
     pub fn typed(id: Identifier) -> Self {
         Self {
-            resource: vec![id.into()],
+            resource: vec![(None, id.into())],
         }
     }
 
     /// Add the provided `ResourceIdentifier` to the inner list of components.
     pub fn add(&mut self, id: ResourceIdentifier) -> &mut Self {
-        self.resource.push(id);
+        self.resource.push((None, id));
         self
     }
 
     /// Add the provided `ResourceIdentifier` to the inner list of components.
     pub fn qualified_name(&mut self, id: ResourceIdentifier) -> &mut Self {
-        self.resource.push(id);
+        self.resource.push((None, id));
         self
     }
 
     /// Add the provided `ResourceIdentifier` to the inner list of components.
     pub fn resource_path(&mut self, id: ResourceIdentifier) -> &mut Self {
-        self.resource.push(id);
+        self.resource.push((None, id));
         self
     }
 
     /// Add the provided `Identifier` to the inner list of components.
     pub fn type_name(&mut self, id: Identifier) -> &mut Self {
-        self.resource.push(id.into());
+        self.resource.push((None, id.into()));
         self
     }
 
     /// Add the provided `Identifier` to the inner list of components.
     pub fn resource_name(&mut self, id: Identifier) -> &mut Self {
-        self.resource.push(id.into());
+        self.resource.push((None, id.into()));
         self
     }
 
     /// Add the provided `Identifier` to the inner list of components.
     pub fn sub_resource_name(&mut self, id: Identifier) -> &mut Self {
-        self.resource.push(id.into());
+        self.resource.push((None, id.into()));
         self
     }
 
     /// Add the provided integer version number to the inner list of components.
     pub fn version(&mut self, v: u32) -> &mut Self {
         self.resource
-            .push(Identifier::new_unchecked(&v.to_string()).into());
+            .push((None, Identifier::new_unchecked(&v.to_string()).into()));
+        self
+    }
+
+    /// Add the provided `ResourceIdentifier` to the inner list of components, recording the
+    /// `separator` that will precede it in the result of `build()`.
+    pub fn segment(&mut self, separator: Separator, id: ResourceIdentifier) -> &mut Self {
+        self.resource.push((Some(separator), id));
+        self
+    }
+
+    /// Add the provided `ResourceIdentifier` to the inner list of components, preceded by '/'.
+    pub fn path_segment(&mut self, id: ResourceIdentifier) -> &mut Self {
+        self.segment(Separator::Path, id)
+    }
+
+    /// Add the provided `ResourceIdentifier` to the inner list of components, preceded by ':'.
+    pub fn qualifier_segment(&mut self, id: ResourceIdentifier) -> &mut Self {
+        self.segment(Separator::Qualifier, id)
+    }
+
+    /// Add each element of an IAM-style path to the inner list of components, each preceded
+    /// by '/'. For example, the path `/division_abc/subdivision_xyz/` is provided as
+    /// `&[division_abc, subdivision_xyz]`, an empty slice represents the root path `/`.
+    pub fn path(&mut self, path: &[Identifier]) -> &mut Self {
+        for id in path {
+            let _ = self.path_segment(id.clone().into());
+        }
         self
     }
 
+    /// Return the inner list of components as a resource identifier, using the separator
+    /// recorded for each component. Components added without a separator are preceded by '/'.
+    pub fn build(&mut self) -> ResourceIdentifier {
+        let mut resource = String::new();
+        for (i, (separator, id)) in self.resource.iter().enumerate() {
+            if i > 0 {
+                resource.push(separator.unwrap_or_default().into());
+            }
+            resource.push_str(id);
+        }
+        ResourceIdentifier::new_unchecked(&resource)
+    }
+
     /// Return the iner list of components as a resource identifier path.
     pub fn build_resource_path(&mut self) -> ResourceIdentifier {
-        ResourceIdentifier::from_path(&self.resource)
+        ResourceIdentifier::from_path(&self.components())
     }
 
     /// Return the iner list of components as a qualified resource identifier.
     pub fn build_qualified_id(&mut self) -> ResourceIdentifier {
-        ResourceIdentifier::from_qualified(&self.resource)
+        ResourceIdentifier::from_qualified(&self.components())
+    }
+
+    fn components(&self) -> Vec<ResourceIdentifier> {
+        self.resource.iter().map(|(_, id)| id.clone()).collect()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl From<Separator> for char {
+    fn from(v: Separator) -> Self {
+        match v {
+            Separator::Path => '/',
+            Separator::Qualifier => ':',
+        }
+    }
+}
+
+impl TryFrom<&ResourceName> for KnownResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        let known = match Service::from_str(&arn.service) {
+            Ok(Service::CloudWatchLogs) => {
+                logs::LogsResource::try_from(arn).map(KnownResource::Logs)
+            }
+            Ok(Service::CognitoIdentity)
+            | Ok(Service::CognitoIdentityProvider)
+            | Ok(Service::CognitoSync) => {
+                cognito::CognitoResource::try_from(arn).map(KnownResource::Cognito)
+            }
+            Ok(Service::DynamoDb) => {
+                dynamodb::DynamoDbResource::try_from(arn).map(KnownResource::DynamoDb)
+            }
+            Ok(Service::Ec2) => ec2::Ec2Resource::try_from(arn).map(KnownResource::Ec2),
+            Ok(Service::EventBridge)
+            | Ok(Service::EventBridgePipes)
+            | Ok(Service::EventBridgeScheduler) => {
+                events::EventsResource::try_from(arn).map(KnownResource::Events)
+            }
+            Ok(Service::IdentityAccessManagement) => {
+                iam::IamResource::try_from(arn).map(KnownResource::Iam)
+            }
+            Ok(Service::KeyManagement) => kms::KmsResource::try_from(arn).map(KnownResource::Kms),
+            Ok(Service::Lambda) => lambda::LambdaResource::try_from(arn).map(KnownResource::Lambda),
+            Ok(Service::S3)
+            | Ok(Service::S3ObjectLambda)
+            | Ok(Service::S3Outposts)
+            | Ok(Service::S3Express) => s3::S3Resource::try_from(arn).map(KnownResource::S3),
+            Ok(Service::SecretsManager) => secretsmanager::SecretsManagerResource::try_from(arn)
+                .map(KnownResource::SecretsManager),
+            Ok(Service::SecurityToken) => sts::StsResource::try_from(arn).map(KnownResource::Sts),
+            Ok(Service::SimpleNotification) => {
+                sns::SnsResource::try_from(arn).map(KnownResource::Sns)
+            }
+            Ok(Service::SimpleQueue) => sqs::SqsResource::try_from(arn).map(KnownResource::Sqs),
+            Ok(Service::SimpleSystemsManager) => {
+                ssm::SsmResource::try_from(arn).map(KnownResource::Ssm)
+            }
+            _ => Ok(KnownResource::Unknown(arn.clone())),
+        };
+        Ok(known.unwrap_or_else(|_| KnownResource::Unknown(arn.clone())))
+    }
+}
+
+impl TryFrom<ResourceName> for KnownResource {
+    type Error = Error;
+
+    fn try_from(arn: ResourceName) -> Result<Self, Self::Error> {
+        KnownResource::try_from(&arn)
+    }
+}
+
+impl ServiceResource for KnownResource {
+    fn service(&self) -> Identifier {
+        match self {
+            KnownResource::Cognito(v) => v.service(),
+            KnownResource::DynamoDb(v) => v.service(),
+            KnownResource::Ec2(v) => v.service(),
+            KnownResource::Events(v) => v.service(),
+            KnownResource::Iam(v) => v.service(),
+            KnownResource::Kms(v) => v.service(),
+            KnownResource::Lambda(v) => v.service(),
+            KnownResource::Logs(v) => v.service(),
+            KnownResource::S3(v) => v.service(),
+            KnownResource::SecretsManager(v) => v.service(),
+            KnownResource::Sns(v) => v.service(),
+            KnownResource::Sqs(v) => v.service(),
+            KnownResource::Ssm(v) => v.service(),
+            KnownResource::Sts(v) => v.service(),
+            KnownResource::Unknown(v) => v.service.clone(),
+        }
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            KnownResource::Cognito(v) => v.resource_type_name(),
+            KnownResource::DynamoDb(v) => v.resource_type_name(),
+            KnownResource::Ec2(v) => v.resource_type_name(),
+            KnownResource::Events(v) => v.resource_type_name(),
+            KnownResource::Iam(v) => v.resource_type_name(),
+            KnownResource::Kms(v) => v.resource_type_name(),
+            KnownResource::Lambda(v) => v.resource_type_name(),
+            KnownResource::Logs(v) => v.resource_type_name(),
+            KnownResource::S3(v) => v.resource_type_name(),
+            KnownResource::SecretsManager(v) => v.resource_type_name(),
+            KnownResource::Sns(v) => v.resource_type_name(),
+            KnownResource::Sqs(v) => v.resource_type_name(),
+            KnownResource::Ssm(v) => v.resource_type_name(),
+            KnownResource::Sts(v) => v.resource_type_name(),
+            KnownResource::Unknown(v) => v.resource.split(['/', ':']).next().unwrap_or_default(),
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        match self {
+            KnownResource::Cognito(v) => v.to_arn(),
+            KnownResource::DynamoDb(v) => v.to_arn(),
+            KnownResource::Ec2(v) => v.to_arn(),
+            KnownResource::Events(v) => v.to_arn(),
+            KnownResource::Iam(v) => v.to_arn(),
+            KnownResource::Kms(v) => v.to_arn(),
+            KnownResource::Lambda(v) => v.to_arn(),
+            KnownResource::Logs(v) => v.to_arn(),
+            KnownResource::S3(v) => v.to_arn(),
+            KnownResource::SecretsManager(v) => v.to_arn(),
+            KnownResource::Sns(v) => v.to_arn(),
+            KnownResource::Sqs(v) => v.to_arn(),
+            KnownResource::Ssm(v) => v.to_arn(),
+            KnownResource::Sts(v) => v.to_arn(),
+            KnownResource::Unknown(v) => v.clone(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+/// The domain of service endpoints in the `aws` and `aws-us-gov` partitions.
+pub(crate) const DOMAIN: &str = ".amazonaws.com";
+
+/// The domain of service endpoints in the `aws-cn` partition.
+pub(crate) const DOMAIN_CHINA: &str = ".amazonaws.com.cn";
+
+/// Used by the service modules, return an error if `arn` is not for `service`.
+pub(crate) fn expect_service(arn: &ResourceName, service: Service) -> Result<(), Error> {
+    let expected: Identifier = service.into();
+    if arn.service == expected {
+        Ok(())
+    } else {
+        Err(Error::ResourceMismatch {
+            expected: expected.to_string(),
+            found: arn.service.to_string(),
+        })
+    }
+}
+
+/// Used by the typed parsers of service modules that cover more than one service, return the
+/// service of `arn`, or an error listing all of the accepted `services` if it is none of them.
+pub(crate) fn expect_one_of_services(
+    arn: &ResourceName,
+    services: &[Service],
+) -> Result<Service, Error> {
+    match Service::from_str(&arn.service) {
+        Ok(service) if services.contains(&service) => Ok(service),
+        _ => Err(Error::ResourceMismatch {
+            expected: services
+                .iter()
+                .map(|service| service.to_string())
+                .collect::<Vec<String>>()
+                .join(" | "),
+            found: arn.service.to_string(),
+        }),
+    }
+}
+
+/// Used by the service builder functions, return an error if `name` is outside the `length`
+/// range, or contains a character rejected by `is_valid`. The wildcard characters `*` and `?`
+/// are always accepted, and the length is not checked for names that contain them, so that
+/// names may be used as patterns in policies.
+pub(crate) fn check_name<F>(
+    name: &str,
+    length: RangeInclusive<usize>,
+    is_valid: F,
+) -> Result<(), Error>
+where
+    F: Fn(char) -> bool,
+{
+    let is_wildcard = |c: char| c == '*' || c == '?';
+    if (name.contains(is_wildcard) || length.contains(&name.len()))
+        && name.chars().all(|c| is_wildcard(c) || is_valid(c))
+    {
+        Ok(())
+    } else {
+        Err(Error::InvalidIdentifier(name.to_string()))
+    }
+}
+
+/// Used with `check_name`, accept the characters common to most resource names; ASCII letters,
+/// digits, `-`, and `_`.
+pub(crate) fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Used with `check_name`, accept the characters of `is_name_char` and also `.`.
+pub(crate) fn is_dotted_name_char(c: char) -> bool {
+    is_name_char(c) || c == '.'
+}
+
+/// Used with `check_name`, accept the characters allowed in IAM user, role, and session names;
+/// ASCII letters, digits, and `+=,.@-_`.
+pub(crate) fn is_iam_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "+=,.@-_".contains(c)
+}
+
+/// Used by the typed parsers in the service modules, return the partition or the default.
+pub(crate) fn partition_of(arn: &ResourceName) -> Identifier {
+    arn.partition
+        .clone()
+        .unwrap_or_else(|| Partition::default().into())
+}
+
+/// Used by the typed parsers in the service modules, return the region or an error if missing.
+pub(crate) fn region_of(arn: &ResourceName) -> Result<Identifier, Error> {
+    match &arn.region {
+        Some(region) if !region.is_empty() => Ok(region.clone()),
+        _ => Err(Error::MissingRegion),
+    }
+}
+
+/// Used by the typed parsers in the service modules, return the account or an error if missing.
+pub(crate) fn account_of(arn: &ResourceName) -> Result<AccountIdentifier, Error> {
+    match &arn.account_id {
+        Some(account) if !account.is_empty() => Ok(account.clone()),
+        _ => Err(Error::MissingAccountId),
+    }
+}
+
+/// Used by the URL conversions in the service modules, return the endpoint domain for a partition.
+pub(crate) fn domain_of(partition: &Identifier) -> Result<&'static str, Error> {
+    match Partition::from_str(partition) {
+        Ok(Partition::Aws) | Ok(Partition::AwsUsGov) => Ok(DOMAIN),
+        Ok(Partition::AwsChina) => Ok(DOMAIN_CHINA),
+        _ => Err(Error::InvalidPartition),
+    }
+}
+
+/// Used by the URL conversions in the service modules, return the partition for an endpoint in
+/// the China domain, or in the given region.
+pub(crate) fn partition_of_endpoint(china: bool, region: Option<&str>) -> Identifier {
+    if china {
+        Partition::AwsChina.into()
+    } else if region.is_some_and(|r| r.starts_with("us-gov-")) {
+        Partition::AwsUsGov.into()
+    } else {
+        Partition::default().into()
     }
 }
 
@@ -299,8 +708,30 @@ impl ResourceBuilder {
 
 pub mod cognito;
 
+pub mod dynamodb;
+
+pub mod ec2;
+
+pub mod events;
+
 pub mod iam;
 
+pub mod kms;
+
 pub mod lambda;
 
+pub mod logs;
+
 pub mod s3;
+
+pub mod secretsmanager;
+
+pub mod sns;
+
+pub mod sqs;
+
+pub mod ssm;
+
+pub mod sts;
+
+pub mod typed;
@@ -1,14 +1,192 @@
 /*!
-Provides a set of simple helper functions to make ResourceNames for the S3 service.
+Provides a set of simple helper functions to make ResourceNames for the S3 service, and the related
+S3 Object Lambda, S3 on Outposts, and S3 Express One Zone services.
 
 These resource definitions ae take from the AWS
-[documentation]( https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazons3.html#amazons3-resources-for-iam-policies)
+[documentation]( https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazons3.html#amazons3-resources-for-iam-policies),
+[S3 Object Lambda](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazons3objectlambda.html#amazons3objectlambda-resources-for-iam-policies),
+[S3 on Outposts](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazons3onoutposts.html#amazons3onoutposts-resources-for-iam-policies),
+and [S3 Express](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazons3express.html#amazons3express-resources-for-iam-policies).
 */
 
-use crate::builder::ArnBuilder;
+use crate::builder::{
+    account_of, check_name, domain_of, expect_one_of_services, is_dotted_name_char, partition_of,
+    partition_of_endpoint, region_of, ArnBuilder, ServiceResource, DOMAIN, DOMAIN_CHINA,
+};
 use crate::known::Partition;
-use crate::known::Service::S3;
-use crate::{AccountIdentifier, Identifier, ResourceIdentifier, ResourceName};
+use crate::known::Service::{self, S3Express, S3ObjectLambda, S3Outposts, S3};
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+#[cfg(feature = "serde_support")]
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the S3 family ResourceNames constructed by the functions in this module.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum S3Resource {
+    /// `arn:${Partition}:s3:::${BucketName}`
+    Bucket {
+        /// The partition component.
+        partition: Identifier,
+        /// The bucket name.
+        bucket: BucketName,
+    },
+    /// `arn:${Partition}:s3:::${BucketName}/${ObjectName}`
+    Object {
+        /// The partition component.
+        partition: Identifier,
+        /// The bucket name.
+        bucket: BucketName,
+        /// The object key, which may include '/' characters.
+        key: ObjectKey,
+    },
+    /// `arn:${Partition}:s3:${Region}:${Account}:job/${JobId}`
+    Job {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The batch operations job identifier.
+        job_id: Identifier,
+    },
+    /// `arn:${Partition}:s3:${Region}:${Account}:accesspoint/${AccessPointName}`
+    AccessPoint {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The access point name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:s3:${Region}:${Account}:accesspoint/${AccessPointName}/object/${ObjectName}`
+    AccessPointObject {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The access point name.
+        name: Identifier,
+        /// The object key, which may include '/' characters.
+        key: ObjectKey,
+    },
+    /// `arn:${Partition}:s3::${Account}:accesspoint/${AccessPointAlias}`
+    MultiRegionAccessPoint {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The access point alias, which ends with `.mrap`.
+        alias: Identifier,
+    },
+    /// `arn:${Partition}:s3-object-lambda:${Region}:${Account}:accesspoint/${AccessPointName}`
+    ObjectLambdaAccessPoint {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The Object Lambda access point name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:s3:${Region}:${Account}:storage-lens/${ConfigId}`
+    StorageLens {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The Storage Lens configuration identifier.
+        config_id: Identifier,
+    },
+    /// `arn:${Partition}:s3-outposts:${Region}:${Account}:outpost/${OutpostId}/accesspoint/${AccessPointName}`
+    OutpostAccessPoint {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The Outpost identifier.
+        outpost_id: Identifier,
+        /// The access point name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:s3-outposts:${Region}:${Account}:outpost/${OutpostId}/endpoint/${EndpointId}`
+    OutpostEndpoint {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The Outpost identifier.
+        outpost_id: Identifier,
+        /// The endpoint identifier.
+        endpoint_id: Identifier,
+    },
+    /// `arn:${Partition}:s3express:${Region}:${Account}:bucket/${BucketName}`
+    DirectoryBucket {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The directory bucket name, which ends with `--x-s3`.
+        bucket: BucketName,
+    },
+}
+
+///
+/// An S3 bucket name. Names are 3 to 63 characters of lowercase letters, digits, '.', and '-',
+/// must begin and end with a letter or digit, may not contain adjacent periods, may not be
+/// formatted as an IP address, and may not use the prefixes or suffixes reserved by S3. Directory
+/// bucket names have the form `${BaseName}--${ZoneId}--x-s3`, a suffix which is reserved for them.
+///
+/// Names containing the wildcard characters `*` and `?` are accepted for use in policies, and
+/// only the character rules are checked.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_support", derive(Deserialize, Serialize))]
+pub struct BucketName(String);
+
+///
+/// An S3 object key. Keys are 1 to 1024 bytes long and, in a ResourceName, may only contain the
+/// printable ASCII characters accepted by `ResourceIdentifier`; keys may include '/' characters.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_support", derive(Deserialize, Serialize))]
+pub struct ObjectKey(String);
+
+///
+/// The form of the HTTPS URL produced by `to_url` for bucket and object ResourceNames.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UrlStyle {
+    /// `https://${BucketName}.s3.${Region}.amazonaws.com/${ObjectName}`
+    #[default]
+    VirtualHosted,
+    /// `https://s3.${Region}.amazonaws.com/${BucketName}/${ObjectName}`
+    Path,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
@@ -17,17 +195,15 @@ use crate::{AccountIdentifier, Identifier, ResourceIdentifier, ResourceName};
 ///
 /// `arn:${Partition}:s3:::${BucketName}`
 ///
-pub fn bucket_in(partition: Identifier, bucket_name: Identifier) -> ResourceName {
-    ArnBuilder::service_id(S3.into())
-        .in_partition_id(partition)
-        .is(bucket_name.into())
-        .into()
+pub fn bucket_in(partition: Identifier, bucket_name: BucketName) -> Result<ResourceName, Error> {
+    check_general_purpose(&bucket_name)?;
+    Ok(s3_arn(partition, bucket_name.into()))
 }
 
 ///
 /// `arn:aws:s3:::${BucketName}`
 ///
-pub fn bucket(bucket_name: Identifier) -> ResourceName {
+pub fn bucket(bucket_name: BucketName) -> Result<ResourceName, Error> {
     bucket_in(Partition::default().into(), bucket_name)
 }
 
@@ -36,40 +212,36 @@ pub fn bucket(bucket_name: Identifier) -> ResourceName {
 ///
 pub fn object_in(
     partition: Identifier,
-    bucket_name: Identifier,
-    object_name: Identifier,
-) -> ResourceName {
-    ArnBuilder::service_id(S3.into())
-        .in_partition_id(partition)
-        .is(ResourceIdentifier::from_id_path(&[
-            bucket_name,
-            object_name,
-        ]))
-        .into()
+    bucket_name: BucketName,
+    object_name: ObjectKey,
+) -> Result<ResourceName, Error> {
+    check_general_purpose(&bucket_name)?;
+    Ok(s3_arn(
+        partition,
+        ResourceIdentifier::from_path(&[bucket_name.into(), object_name.into()]),
+    ))
 }
 
 ///
 /// `arn:aws:s3:::${BucketName}/${ObjectName}`
 ///
-pub fn object(bucket_name: Identifier, object_name: Identifier) -> ResourceName {
+pub fn object(bucket_name: BucketName, object_name: ObjectKey) -> Result<ResourceName, Error> {
     object_in(Partition::default().into(), bucket_name, object_name)
 }
 
 ///
-/// `arn:aws:s3:::${BucketName}/${ObjectName}`
+/// `arn:${Partition}:s3:::${BucketName}/${ObjectName}`
 ///
-/// This function will panic if `bucket` is not an ResourceName for an S3 bucket.
+/// This function will return `Error::ResourceMismatch` if `bucket` is not an ResourceName for an
+/// S3 bucket.
 ///
-pub fn object_from(bucket: &ResourceName, object_name: Identifier) -> ResourceName {
-    if bucket.service != S3.into() {
-        panic!(
-            "You can't make an S3 object from a {} ResourceName.",
-            bucket.service
-        );
-    }
-    ResourceName {
-        resource: ResourceIdentifier::from_path(&[bucket.resource.clone(), object_name.into()]),
-        ..bucket.clone()
+pub fn object_from(bucket: &ResourceName, object_name: ObjectKey) -> Result<ResourceName, Error> {
+    match S3Resource::try_from(bucket)? {
+        S3Resource::Bucket { partition, bucket } => object_in(partition, bucket, object_name),
+        other => Err(Error::ResourceMismatch {
+            expected: "bucket".to_string(),
+            found: other.resource_type_name().to_string(),
+        }),
     }
 }
 
@@ -81,18 +253,1020 @@ pub fn job_in(
     region: Identifier,
     account: AccountIdentifier,
     job_id: Identifier,
-) -> ResourceName {
+) -> Result<ResourceName, Error> {
+    check_name(&job_id, 1..=64, |c| c.is_ascii_alphanumeric() || c == '-')?;
+    Ok(regional_arn(
+        S3,
+        partition,
+        region,
+        account,
+        typed_path("job", job_id),
+    ))
+}
+
+///
+/// `arn:aws:s3:${Region}:${Account}:job/${JobId}`
+///
+pub fn job(
+    region: Identifier,
+    account: AccountIdentifier,
+    job_id: Identifier,
+) -> Result<ResourceName, Error> {
+    job_in(Partition::default().into(), region, account, job_id)
+}
+
+///
+/// `arn:${Partition}:s3:${Region}:${Account}:accesspoint/${AccessPointName}`
+///
+pub fn access_point_in(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    access_point_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_access_point_name(&access_point_name, 50)?;
+    Ok(regional_arn(
+        S3,
+        partition,
+        region,
+        account,
+        typed_path("accesspoint", access_point_name),
+    ))
+}
+
+///
+/// `arn:aws:s3:${Region}:${Account}:accesspoint/${AccessPointName}`
+///
+pub fn access_point(
+    region: Identifier,
+    account: AccountIdentifier,
+    access_point_name: Identifier,
+) -> Result<ResourceName, Error> {
+    access_point_in(
+        Partition::default().into(),
+        region,
+        account,
+        access_point_name,
+    )
+}
+
+///
+/// `arn:${Partition}:s3:${Region}:${Account}:accesspoint/${AccessPointName}/object/${ObjectName}`
+///
+pub fn access_point_object_in(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    access_point_name: Identifier,
+    object_name: ObjectKey,
+) -> Result<ResourceName, Error> {
+    check_access_point_name(&access_point_name, 50)?;
+    Ok(regional_arn(
+        S3,
+        partition,
+        region,
+        account,
+        access_point_object_path(access_point_name, object_name),
+    ))
+}
+
+///
+/// `arn:aws:s3:${Region}:${Account}:accesspoint/${AccessPointName}/object/${ObjectName}`
+///
+pub fn access_point_object(
+    region: Identifier,
+    account: AccountIdentifier,
+    access_point_name: Identifier,
+    object_name: ObjectKey,
+) -> Result<ResourceName, Error> {
+    access_point_object_in(
+        Partition::default().into(),
+        region,
+        account,
+        access_point_name,
+        object_name,
+    )
+}
+
+///
+/// `arn:${Partition}:s3::${Account}:accesspoint/${AccessPointAlias}`
+///
+pub fn multi_region_access_point_in(
+    partition: Identifier,
+    account: AccountIdentifier,
+    access_point_alias: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&access_point_alias, 6..=63, |c| {
+        c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.'
+    })?;
+    if !access_point_alias.contains(['*', '?']) && !access_point_alias.ends_with(MRAP_SUFFIX) {
+        return Err(Error::InvalidIdentifier(access_point_alias.to_string()));
+    }
+    Ok(ArnBuilder::service_id(S3.into())
+        .in_partition_id(partition)
+        .owned_by(account)
+        .is(typed_path("accesspoint", access_point_alias))
+        .into())
+}
+
+///
+/// `arn:aws:s3::${Account}:accesspoint/${AccessPointAlias}`
+///
+pub fn multi_region_access_point(
+    account: AccountIdentifier,
+    access_point_alias: Identifier,
+) -> Result<ResourceName, Error> {
+    multi_region_access_point_in(Partition::default().into(), account, access_point_alias)
+}
+
+///
+/// `arn:${Partition}:s3-object-lambda:${Region}:${Account}:accesspoint/${AccessPointName}`
+///
+pub fn object_lambda_access_point_in(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    access_point_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_access_point_name(&access_point_name, 45)?;
+    Ok(regional_arn(
+        S3ObjectLambda,
+        partition,
+        region,
+        account,
+        typed_path("accesspoint", access_point_name),
+    ))
+}
+
+///
+/// `arn:aws:s3-object-lambda:${Region}:${Account}:accesspoint/${AccessPointName}`
+///
+pub fn object_lambda_access_point(
+    region: Identifier,
+    account: AccountIdentifier,
+    access_point_name: Identifier,
+) -> Result<ResourceName, Error> {
+    object_lambda_access_point_in(
+        Partition::default().into(),
+        region,
+        account,
+        access_point_name,
+    )
+}
+
+///
+/// `arn:${Partition}:s3:${Region}:${Account}:storage-lens/${ConfigId}`
+///
+pub fn storage_lens_in(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    config_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&config_id, 1..=64, is_dotted_name_char)?;
+    Ok(regional_arn(
+        S3,
+        partition,
+        region,
+        account,
+        typed_path("storage-lens", config_id),
+    ))
+}
+
+///
+/// `arn:aws:s3:${Region}:${Account}:storage-lens/${ConfigId}`
+///
+pub fn storage_lens(
+    region: Identifier,
+    account: AccountIdentifier,
+    config_id: Identifier,
+) -> Result<ResourceName, Error> {
+    storage_lens_in(Partition::default().into(), region, account, config_id)
+}
+
+///
+/// `arn:${Partition}:s3-outposts:${Region}:${Account}:outpost/${OutpostId}/accesspoint/${AccessPointName}`
+///
+pub fn outpost_access_point_in(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    outpost_id: Identifier,
+    access_point_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_outpost_id(&outpost_id)?;
+    check_access_point_name(&access_point_name, 50)?;
+    Ok(outpost_arn(
+        partition,
+        region,
+        account,
+        outpost_id,
+        "accesspoint",
+        access_point_name,
+    ))
+}
+
+///
+/// `arn:aws:s3-outposts:${Region}:${Account}:outpost/${OutpostId}/accesspoint/${AccessPointName}`
+///
+pub fn outpost_access_point(
+    region: Identifier,
+    account: AccountIdentifier,
+    outpost_id: Identifier,
+    access_point_name: Identifier,
+) -> Result<ResourceName, Error> {
+    outpost_access_point_in(
+        Partition::default().into(),
+        region,
+        account,
+        outpost_id,
+        access_point_name,
+    )
+}
+
+///
+/// `arn:${Partition}:s3-outposts:${Region}:${Account}:outpost/${OutpostId}/endpoint/${EndpointId}`
+///
+pub fn outpost_endpoint_in(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    outpost_id: Identifier,
+    endpoint_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_outpost_id(&outpost_id)?;
+    check_name(&endpoint_id, 1..=64, |c| {
+        c.is_ascii_alphanumeric() || c == '-'
+    })?;
+    Ok(outpost_arn(
+        partition,
+        region,
+        account,
+        outpost_id,
+        "endpoint",
+        endpoint_id,
+    ))
+}
+
+///
+/// `arn:aws:s3-outposts:${Region}:${Account}:outpost/${OutpostId}/endpoint/${EndpointId}`
+///
+pub fn outpost_endpoint(
+    region: Identifier,
+    account: AccountIdentifier,
+    outpost_id: Identifier,
+    endpoint_id: Identifier,
+) -> Result<ResourceName, Error> {
+    outpost_endpoint_in(
+        Partition::default().into(),
+        region,
+        account,
+        outpost_id,
+        endpoint_id,
+    )
+}
+
+///
+/// `arn:${Partition}:s3express:${Region}:${Account}:bucket/${BucketName}`
+///
+pub fn directory_bucket_in(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    bucket_name: BucketName,
+) -> Result<ResourceName, Error> {
+    if !bucket_name.has_wildcards() && !bucket_name.is_directory_bucket() {
+        return Err(Error::InvalidIdentifier(bucket_name.to_string()));
+    }
+    Ok(regional_arn(
+        S3Express,
+        partition,
+        region,
+        account,
+        typed_path("bucket", bucket_name.into()),
+    ))
+}
+
+///
+/// `arn:aws:s3express:${Region}:${Account}:bucket/${BucketName}`
+///
+pub fn directory_bucket(
+    region: Identifier,
+    account: AccountIdentifier,
+    bucket_name: BucketName,
+) -> Result<ResourceName, Error> {
+    directory_bucket_in(Partition::default().into(), region, account, bucket_name)
+}
+
+///
+/// Convert an `s3://${BucketName}[/${ObjectName}]` URI, as used by the AWS CLI, into a bucket or
+/// object ResourceName in the default partition. The `s3a` and `s3n` schemes used by Hadoop are
+/// also accepted. Object keys in these URIs are not percent-encoded.
+///
+pub fn from_uri(uri: &str) -> Result<ResourceName, Error> {
+    let invalid = || Error::InvalidUrl(uri.to_string());
+    let rest = URI_SCHEMES
+        .iter()
+        .find_map(|scheme| uri.strip_prefix(scheme))
+        .ok_or_else(invalid)?;
+    match rest.split_once('/') {
+        None | Some((_, "")) => bucket_or_object(
+            Partition::default().into(),
+            rest.trim_end_matches('/'),
+            None,
+        ),
+        Some((bucket, key)) => bucket_or_object(Partition::default().into(), bucket, Some(key)),
+    }
+    .map_err(|_| invalid())
+}
+
+///
+/// Convert a bucket or object ResourceName into an `s3://${BucketName}[/${ObjectName}]` URI.
+///
+pub fn to_uri(arn: &ResourceName) -> Result<String, Error> {
+    match S3Resource::try_from(arn)? {
+        S3Resource::Bucket { bucket, .. } => Ok(format!("{}{}", URI_SCHEMES[0], bucket)),
+        S3Resource::Object { bucket, key, .. } => {
+            Ok(format!("{}{}/{}", URI_SCHEMES[0], bucket, key))
+        }
+        other => Err(Error::ResourceMismatch {
+            expected: "bucket".to_string(),
+            found: other.resource_type_name().to_string(),
+        }),
+    }
+}
+
+///
+/// Convert an HTTPS URL into a ResourceName. Virtual-hosted and path-style URLs produce a bucket
+/// or object ResourceName, and access point URLs, of the form
+/// `https://${AccessPointName}-${Account}.s3-accesspoint.${Region}.amazonaws.com/${ObjectName}`,
+/// produce an access point or access point object ResourceName. The partition is taken from the
+/// domain, `amazonaws.com.cn` for `aws-cn`, and from `us-gov-` regions for `aws-us-gov`. Object
+/// keys are percent-decoded.
+///
+pub fn from_url(url: &str) -> Result<ResourceName, Error> {
+    let invalid = || Error::InvalidUrl(url.to_string());
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .ok_or_else(invalid)?;
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let host = host.split(':').next().unwrap_or_default();
+    let path = percent_decode(path).ok_or_else(invalid)?;
+    let (host, china) = match host.strip_suffix(DOMAIN_CHINA) {
+        Some(host) => (host, true),
+        None => (host.strip_suffix(DOMAIN).ok_or_else(invalid)?, false),
+    };
+    let labels: Vec<&str> = host.split('.').collect();
+    let partition = |region: Option<&str>| partition_of_endpoint(china, region);
+
+    if let [access_point, "s3-accesspoint", .., region] = labels.as_slice() {
+        let (name, account) = access_point.rsplit_once('-').ok_or_else(invalid)?;
+        let name = Identifier::from_str(name).map_err(|_| invalid())?;
+        let resource = if path.is_empty() {
+            typed_path("accesspoint", name)
+        } else {
+            access_point_object_path(name, ObjectKey::from_str(&path).map_err(|_| invalid())?)
+        };
+        return Ok(regional_arn(
+            S3,
+            partition(Some(region)),
+            Identifier::from_str(region).map_err(|_| invalid())?,
+            AccountIdentifier::from_str(account).map_err(|_| invalid())?,
+            resource,
+        ));
+    }
+
+    let endpoint = labels
+        .iter()
+        .rposition(|l| *l == "s3" || l.starts_with("s3-"))
+        .ok_or_else(invalid)?;
+    let region = match labels[endpoint].strip_prefix("s3-") {
+        Some("global") => return Err(invalid()),
+        Some("external-1") => Some("us-east-1"),
+        Some(region) => Some(region),
+        None => labels[endpoint + 1..]
+            .iter()
+            .copied()
+            .find(|l| *l != "dualstack"),
+    };
+    let partition = partition(region);
+    if endpoint == 0 {
+        match path.split_once('/') {
+            None | Some((_, "")) => bucket_or_object(partition, path.trim_end_matches('/'), None),
+            Some((bucket, key)) => bucket_or_object(partition, bucket, Some(key)),
+        }
+    } else {
+        let bucket = labels[..endpoint].join(".");
+        bucket_or_object(
+            partition,
+            &bucket,
+            Some(path.as_str()).filter(|p| !p.is_empty()),
+        )
+    }
+    .map_err(|_| invalid())
+}
+
+///
+/// Convert a bucket, object, access point, or access point object ResourceName into an HTTPS URL.
+/// For buckets and objects the `region` selects the regional endpoint, or the global endpoint if
+/// `None`, and `style` selects a virtual-hosted or path-style URL; access points always use the
+/// region in the ResourceName and a virtual-hosted URL. Object keys are percent-encoded.
+///
+pub fn to_url(
+    arn: &ResourceName,
+    region: Option<Identifier>,
+    style: UrlStyle,
+) -> Result<String, Error> {
+    let (bucket, key) = match S3Resource::try_from(arn)? {
+        S3Resource::Bucket { bucket, .. } => (bucket, None),
+        S3Resource::Object { bucket, key, .. } => (bucket, Some(key)),
+        S3Resource::AccessPoint {
+            partition,
+            region,
+            account,
+            name,
+        } => return access_point_url(&partition, &region, &account, &name, None),
+        S3Resource::AccessPointObject {
+            partition,
+            region,
+            account,
+            name,
+            key,
+        } => return access_point_url(&partition, &region, &account, &name, Some(&key)),
+        other => {
+            return Err(Error::ResourceMismatch {
+                expected: "bucket".to_string(),
+                found: other.resource_type_name().to_string(),
+            })
+        }
+    };
+    let domain = domain_of(&partition_of(arn))?;
+    let endpoint = match region {
+        Some(region) => format!("s3.{}{}", region, domain),
+        None => format!("s3{}", domain),
+    };
+    let key = key.map(|key| percent_encode(&key)).unwrap_or_default();
+    Ok(match style {
+        UrlStyle::VirtualHosted => format!("https://{}.{}/{}", bucket, endpoint, key),
+        UrlStyle::Path if key.is_empty() => format!("https://{}/{}", endpoint, bucket),
+        UrlStyle::Path => format!("https://{}/{}/{}", endpoint, bucket, key),
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const MRAP_SUFFIX: &str = ".mrap";
+
+const DIRECTORY_BUCKET_SUFFIX: &str = "--x-s3";
+
+const URI_SCHEMES: &[&str] = &["s3://", "s3a://", "s3n://"];
+
+const BUCKET_RESERVED_PREFIXES: &[&str] = &["xn--", "sthree-", "amzn-s3-demo-"];
+
+const BUCKET_RESERVED_SUFFIXES: &[&str] = &["-s3alias", "--ol-s3", MRAP_SUFFIX, "--table-s3"];
+
+const OBJECT_KEY_MAX_LENGTH: usize = 1024;
+
+impl Display for BucketName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for BucketName {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if Self::is_valid(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(Error::InvalidIdentifier(s.to_string()))
+        }
+    }
+}
+
+impl From<BucketName> for Identifier {
+    fn from(v: BucketName) -> Self {
+        Identifier::new_unchecked(&v.0)
+    }
+}
+
+impl From<BucketName> for ResourceIdentifier {
+    fn from(v: BucketName) -> Self {
+        ResourceIdentifier::new_unchecked(&v.0)
+    }
+}
+
+impl Deref for BucketName {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IdentifierLike for BucketName {
+    fn new_unchecked(s: &str) -> Self {
+        Self(s.to_string())
+    }
+
+    fn is_valid(s: &str) -> bool {
+        if s.contains(['*', '?']) {
+            return s.len() <= 63 && s.chars().all(|c| c == '*' || c == '?' || is_bucket_char(c));
+        }
+        let directory_ok = match s.strip_suffix(DIRECTORY_BUCKET_SUFFIX) {
+            Some(rest) => {
+                !s.contains('.')
+                    && rest
+                        .rsplit_once("--")
+                        .is_some_and(|(base, zone)| !base.is_empty() && !zone.is_empty())
+            }
+            None => true,
+        };
+        (3..=63).contains(&s.len())
+            && s.chars().all(is_bucket_char)
+            && s.starts_with(|c: char| c.is_ascii_alphanumeric())
+            && s.ends_with(|c: char| c.is_ascii_alphanumeric())
+            && !s.contains("..")
+            && !is_ip_address(s)
+            && !BUCKET_RESERVED_PREFIXES.iter().any(|p| s.starts_with(p))
+            && !BUCKET_RESERVED_SUFFIXES.iter().any(|p| s.ends_with(p))
+            && directory_ok
+    }
+}
+
+impl BucketName {
+    /// Return `true` if this is the name of an S3 Express One Zone directory bucket, else
+    /// `false`.
+    pub fn is_directory_bucket(&self) -> bool {
+        self.0.ends_with(DIRECTORY_BUCKET_SUFFIX)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ObjectKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for ObjectKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if Self::is_valid(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(Error::InvalidResource(s.to_string()))
+        }
+    }
+}
+
+impl From<ObjectKey> for ResourceIdentifier {
+    fn from(v: ObjectKey) -> Self {
+        ResourceIdentifier::new_unchecked(&v.0)
+    }
+}
+
+impl Deref for ObjectKey {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IdentifierLike for ObjectKey {
+    fn new_unchecked(s: &str) -> Self {
+        Self(s.to_string())
+    }
+
+    fn is_valid(s: &str) -> bool {
+        s.len() <= OBJECT_KEY_MAX_LENGTH && ResourceIdentifier::is_valid(s)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl TryFrom<&ResourceName> for S3Resource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        let partition = partition_of(arn);
+        let invalid = || Error::InvalidResource(arn.resource.to_string());
+        let parts: Vec<&str> = arn.resource.splitn(4, '/').collect();
+        let has_region = arn.region.as_ref().is_some_and(|r| !r.is_empty());
+        let has_account = arn.account_id.as_ref().is_some_and(|a| !a.is_empty());
+        match expect_one_of_services(arn, &[S3, S3ObjectLambda, S3Outposts, S3Express])? {
+            S3 if !has_region && !has_account => match arn.resource.split_once('/') {
+                None => Ok(S3Resource::Bucket {
+                    partition,
+                    bucket: BucketName::from_str(&arn.resource).map_err(|_| invalid())?,
+                }),
+                Some((bucket, key)) => Ok(S3Resource::Object {
+                    partition,
+                    bucket: BucketName::from_str(bucket).map_err(|_| invalid())?,
+                    key: ObjectKey::from_str(key).map_err(|_| invalid())?,
+                }),
+            },
+            S3 => match parts.as_slice() {
+                ["job", job_id] => Ok(S3Resource::Job {
+                    partition,
+                    region: region_of(arn)?,
+                    account: account_of(arn)?,
+                    job_id: Identifier::from_str(job_id)?,
+                }),
+                ["accesspoint", alias] if alias.ends_with(MRAP_SUFFIX) => {
+                    if has_region {
+                        return Err(Error::RegionNotAllowed);
+                    }
+                    Ok(S3Resource::MultiRegionAccessPoint {
+                        partition,
+                        account: account_of(arn)?,
+                        alias: Identifier::from_str(alias)?,
+                    })
+                }
+                ["accesspoint", name] => Ok(S3Resource::AccessPoint {
+                    partition,
+                    region: region_of(arn)?,
+                    account: account_of(arn)?,
+                    name: Identifier::from_str(name)?,
+                }),
+                ["accesspoint", name, "object", key] => Ok(S3Resource::AccessPointObject {
+                    partition,
+                    region: region_of(arn)?,
+                    account: account_of(arn)?,
+                    name: Identifier::from_str(name)?,
+                    key: ObjectKey::from_str(key).map_err(|_| invalid())?,
+                }),
+                ["storage-lens", config_id] => Ok(S3Resource::StorageLens {
+                    partition,
+                    region: region_of(arn)?,
+                    account: account_of(arn)?,
+                    config_id: Identifier::from_str(config_id)?,
+                }),
+                ["accesspoint" | "job" | "storage-lens", ..] => Err(invalid()),
+                _ if has_region => Err(Error::RegionNotAllowed),
+                _ => Err(Error::AccountIdNotAllowed),
+            },
+            S3ObjectLambda => match parts.as_slice() {
+                ["accesspoint", name] => Ok(S3Resource::ObjectLambdaAccessPoint {
+                    partition,
+                    region: region_of(arn)?,
+                    account: account_of(arn)?,
+                    name: Identifier::from_str(name)?,
+                }),
+                _ => Err(invalid()),
+            },
+            S3Outposts => match parts.as_slice() {
+                ["outpost", outpost_id, "accesspoint", name] => {
+                    Ok(S3Resource::OutpostAccessPoint {
+                        partition,
+                        region: region_of(arn)?,
+                        account: account_of(arn)?,
+                        outpost_id: Identifier::from_str(outpost_id)?,
+                        name: Identifier::from_str(name)?,
+                    })
+                }
+                ["outpost", outpost_id, "endpoint", endpoint_id] => {
+                    Ok(S3Resource::OutpostEndpoint {
+                        partition,
+                        region: region_of(arn)?,
+                        account: account_of(arn)?,
+                        outpost_id: Identifier::from_str(outpost_id)?,
+                        endpoint_id: Identifier::from_str(endpoint_id)?,
+                    })
+                }
+                _ => Err(invalid()),
+            },
+            S3Express => match parts.as_slice() {
+                ["bucket", bucket] => Ok(S3Resource::DirectoryBucket {
+                    partition,
+                    region: region_of(arn)?,
+                    account: account_of(arn)?,
+                    bucket: BucketName::from_str(bucket).map_err(|_| invalid())?,
+                }),
+                _ => Err(invalid()),
+            },
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl From<&S3Resource> for ResourceName {
+    fn from(v: &S3Resource) -> Self {
+        match v.clone() {
+            S3Resource::Bucket { partition, bucket } => s3_arn(partition, bucket.into()),
+            S3Resource::Object {
+                partition,
+                bucket,
+                key,
+            } => s3_arn(
+                partition,
+                ResourceIdentifier::from_path(&[bucket.into(), key.into()]),
+            ),
+            S3Resource::Job {
+                partition,
+                region,
+                account,
+                job_id,
+            } => regional_arn(S3, partition, region, account, typed_path("job", job_id)),
+            S3Resource::AccessPoint {
+                partition,
+                region,
+                account,
+                name,
+            } => regional_arn(
+                S3,
+                partition,
+                region,
+                account,
+                typed_path("accesspoint", name),
+            ),
+            S3Resource::AccessPointObject {
+                partition,
+                region,
+                account,
+                name,
+                key,
+            } => regional_arn(
+                S3,
+                partition,
+                region,
+                account,
+                access_point_object_path(name, key),
+            ),
+            S3Resource::MultiRegionAccessPoint {
+                partition,
+                account,
+                alias,
+            } => ArnBuilder::service_id(S3.into())
+                .in_partition_id(partition)
+                .owned_by(account)
+                .is(typed_path("accesspoint", alias))
+                .into(),
+            S3Resource::ObjectLambdaAccessPoint {
+                partition,
+                region,
+                account,
+                name,
+            } => regional_arn(
+                S3ObjectLambda,
+                partition,
+                region,
+                account,
+                typed_path("accesspoint", name),
+            ),
+            S3Resource::StorageLens {
+                partition,
+                region,
+                account,
+                config_id,
+            } => regional_arn(
+                S3,
+                partition,
+                region,
+                account,
+                typed_path("storage-lens", config_id),
+            ),
+            S3Resource::OutpostAccessPoint {
+                partition,
+                region,
+                account,
+                outpost_id,
+                name,
+            } => outpost_arn(partition, region, account, outpost_id, "accesspoint", name),
+            S3Resource::OutpostEndpoint {
+                partition,
+                region,
+                account,
+                outpost_id,
+                endpoint_id,
+            } => outpost_arn(
+                partition,
+                region,
+                account,
+                outpost_id,
+                "endpoint",
+                endpoint_id,
+            ),
+            S3Resource::DirectoryBucket {
+                partition,
+                region,
+                account,
+                bucket,
+            } => regional_arn(
+                S3Express,
+                partition,
+                region,
+                account,
+                typed_path("bucket", bucket.into()),
+            ),
+        }
+    }
+}
+
+impl From<S3Resource> for ResourceName {
+    fn from(v: S3Resource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for S3Resource {
+    fn service(&self) -> Identifier {
+        match self {
+            S3Resource::ObjectLambdaAccessPoint { .. } => S3ObjectLambda.into(),
+            S3Resource::OutpostAccessPoint { .. } | S3Resource::OutpostEndpoint { .. } => {
+                S3Outposts.into()
+            }
+            S3Resource::DirectoryBucket { .. } => S3Express.into(),
+            _ => S3.into(),
+        }
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            S3Resource::Bucket { .. } => "bucket",
+            S3Resource::Object { .. } => "object",
+            S3Resource::Job { .. } => "job",
+            S3Resource::AccessPoint { .. } => "accesspoint",
+            S3Resource::AccessPointObject { .. } => "object",
+            S3Resource::MultiRegionAccessPoint { .. } => "accesspoint",
+            S3Resource::ObjectLambdaAccessPoint { .. } => "accesspoint",
+            S3Resource::StorageLens { .. } => "storage-lens",
+            S3Resource::OutpostAccessPoint { .. } => "accesspoint",
+            S3Resource::OutpostEndpoint { .. } => "endpoint",
+            S3Resource::DirectoryBucket { .. } => "bucket",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn s3_arn(partition: Identifier, resource: ResourceIdentifier) -> ResourceName {
     ArnBuilder::service_id(S3.into())
+        .in_partition_id(partition)
+        .is(resource)
+        .into()
+}
+
+fn regional_arn(
+    service: Service,
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    resource: ResourceIdentifier,
+) -> ResourceName {
+    ArnBuilder::service_id(service.into())
         .in_partition_id(partition)
         .in_region_id(region)
         .owned_by(account)
-        .is(job_id.into())
+        .is(resource)
         .into()
 }
 
-///
-/// `arn:aws:s3:${Region}:${Account}:job/${JobId}`
-///
-pub fn job(region: Identifier, account: AccountIdentifier, job_id: Identifier) -> ResourceName {
-    job_in(Partition::default().into(), region, account, job_id)
+fn outpost_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    outpost_id: Identifier,
+    resource_type: &str,
+    name: Identifier,
+) -> ResourceName {
+    regional_arn(
+        S3Outposts,
+        partition,
+        region,
+        account,
+        ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("outpost"),
+            outpost_id,
+            Identifier::new_unchecked(resource_type),
+            name,
+        ]),
+    )
+}
+
+fn typed_path(resource_type: &str, name: Identifier) -> ResourceIdentifier {
+    ResourceIdentifier::from_id_path(&[Identifier::new_unchecked(resource_type), name])
+}
+
+fn access_point_object_path(name: Identifier, key: ObjectKey) -> ResourceIdentifier {
+    ResourceIdentifier::from_path(&[
+        typed_path("accesspoint", name),
+        ResourceIdentifier::new_unchecked("object"),
+        key.into(),
+    ])
+}
+
+fn check_general_purpose(name: &BucketName) -> Result<(), Error> {
+    if name.is_directory_bucket() {
+        Err(Error::InvalidIdentifier(name.to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+fn is_bucket_char(c: char) -> bool {
+    c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.' || c == '-'
+}
+
+fn is_ip_address(s: &str) -> bool {
+    let octets: Vec<&str> = s.split('.').collect();
+    octets.len() == 4
+        && octets
+            .iter()
+            .all(|o| !o.is_empty() && o.len() <= 3 && o.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn check_access_point_name(name: &Identifier, max_length: usize) -> Result<(), Error> {
+    check_name(name, 3..=max_length, |c| {
+        c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'
+    })?;
+    if name.starts_with('-') || name.ends_with('-') {
+        Err(Error::InvalidIdentifier(name.to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_outpost_id(id: &Identifier) -> Result<(), Error> {
+    check_name(id, 20..=20, |c| c.is_ascii_alphanumeric() || c == '-')?;
+    if id.contains(['*', '?']) || id.starts_with("op-") {
+        Ok(())
+    } else {
+        Err(Error::InvalidIdentifier(id.to_string()))
+    }
+}
+
+fn bucket_or_object(
+    partition: Identifier,
+    bucket: &str,
+    key: Option<&str>,
+) -> Result<ResourceName, Error> {
+    let bucket = BucketName::from_str(bucket)?;
+    Ok(match key {
+        None => s3_arn(partition, bucket.into()),
+        Some(key) => s3_arn(
+            partition,
+            ResourceIdentifier::from_path(&[bucket.into(), ObjectKey::from_str(key)?.into()]),
+        ),
+    })
+}
+
+fn access_point_url(
+    partition: &Identifier,
+    region: &Identifier,
+    account: &AccountIdentifier,
+    name: &Identifier,
+    key: Option<&ObjectKey>,
+) -> Result<String, Error> {
+    Ok(format!(
+        "https://{}-{}.s3-accesspoint.{}{}/{}",
+        name,
+        account,
+        region,
+        domain_of(partition)?,
+        key.map(|key| percent_encode(key)).unwrap_or_default()
+    ))
+}
+
+fn percent_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn percent_decode(s: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut rest = s.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
 }
@@ -0,0 +1,256 @@
+/*!
+Provides a set of simple helper functions to make ResourceNames for the Secrets Manager service.
+
+These resource definitions ae take from the AWS
+[documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_awssecretsmanager.html#awssecretsmanager-resources-for-iam-policies).
+
+Secrets Manager appends a hyphen and six random characters to the secret name in the ResourceName
+of a secret, and `secret_pattern` produces the `${SecretName}-??????` pattern that matches any
+suffix in an IAM policy. A name that itself ends in a hyphen and six characters cannot be
+distinguished from a suffixed name, and the Secrets Manager API also accepts partial ResourceNames
+without the suffix, so `SecretsManagerResource::try_from` only separates the `??????` pattern from
+the name. Use `SecretsManagerResource::from_complete` for a ResourceName known to include the
+suffix, such as one returned by the Secrets Manager API.
+*/
+
+use crate::builder::{
+    account_of, check_name, expect_service, partition_of, region_of, ArnBuilder, ServiceResource,
+};
+use crate::known::Service::SecretsManager;
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the Secrets Manager ResourceNames constructed by the functions in this module.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum SecretsManagerResource {
+    /// `arn:${Partition}:secretsmanager:${Region}:${Account}:secret:${SecretName}[-${Suffix}]`
+    Secret {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The secret name, which may include `/` characters.
+        name: ResourceIdentifier,
+        /// The six character suffix added by Secrets Manager, or the `??????` pattern, if it has
+        /// been separated from the name.
+        suffix: Option<Identifier>,
+    },
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// `arn:${Partition}:secretsmanager:${Region}:${Account}:secret:${SecretName}-${Suffix}`
+///
+pub fn secret(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: ResourceIdentifier,
+    suffix: Identifier,
+) -> Result<ResourceName, Error> {
+    check_secret_name(&name)?;
+    check_name(&suffix, SUFFIX_LENGTH..=SUFFIX_LENGTH, |c| {
+        c.is_ascii_alphanumeric()
+    })?;
+    Ok(secret_arn(partition, region, account, name, Some(suffix)))
+}
+
+///
+/// `arn:${Partition}:secretsmanager:${Region}:${Account}:secret:${SecretName}`
+///
+/// A partial ResourceName, without the suffix, as accepted by the Secrets Manager API.
+///
+pub fn partial_secret(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: ResourceIdentifier,
+) -> Result<ResourceName, Error> {
+    check_secret_name(&name)?;
+    Ok(secret_arn(partition, region, account, name, None))
+}
+
+///
+/// `arn:${Partition}:secretsmanager:${Region}:${Account}:secret:${SecretName}-??????`
+///
+/// A pattern, for use in IAM policies, that matches the secret with any suffix, but not other
+/// secrets whose names start with `name`.
+///
+pub fn secret_pattern(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: ResourceIdentifier,
+) -> Result<ResourceName, Error> {
+    check_secret_name(&name)?;
+    Ok(secret_arn(
+        partition,
+        region,
+        account,
+        name,
+        Some(Identifier::new_unchecked(SUFFIX_PATTERN)),
+    ))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const SUFFIX_LENGTH: usize = 6;
+
+const SUFFIX_PATTERN: &str = "??????";
+
+impl SecretsManagerResource {
+    ///
+    /// Parse a complete secret ResourceName, such as one returned by the Secrets Manager API,
+    /// separating the six character suffix from the secret name. Returns an error if the
+    /// ResourceName does not end in a suffix.
+    ///
+    pub fn from_complete(arn: &ResourceName) -> Result<Self, Error> {
+        parse_secret(arn, true)
+    }
+
+    /// Returns `true` if this secret has the name `name`, ignoring the suffix if it has been
+    /// separated from the name.
+    pub fn matches_name(&self, name: &str) -> bool {
+        match self {
+            SecretsManagerResource::Secret {
+                name: secret_name, ..
+            } => &**secret_name == name,
+        }
+    }
+}
+
+impl TryFrom<&ResourceName> for SecretsManagerResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        parse_secret(arn, false)
+    }
+}
+
+impl From<&SecretsManagerResource> for ResourceName {
+    fn from(v: &SecretsManagerResource) -> Self {
+        match v.clone() {
+            SecretsManagerResource::Secret {
+                partition,
+                region,
+                account,
+                name,
+                suffix,
+            } => secret_arn(partition, region, account, name, suffix),
+        }
+    }
+}
+
+impl From<SecretsManagerResource> for ResourceName {
+    fn from(v: SecretsManagerResource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for SecretsManagerResource {
+    fn service(&self) -> Identifier {
+        SecretsManager.into()
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            SecretsManagerResource::Secret { .. } => "secret",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn secret_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: ResourceIdentifier,
+    suffix: Option<Identifier>,
+) -> ResourceName {
+    let name = match suffix {
+        Some(suffix) => ResourceIdentifier::new_unchecked(&format!("{}-{}", name, suffix)),
+        None => name,
+    };
+    ArnBuilder::service_id(SecretsManager.into())
+        .in_partition_id(partition)
+        .in_region_id(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_qualified(&[
+            ResourceIdentifier::new_unchecked("secret"),
+            name,
+        ]))
+        .into()
+}
+
+// Only a `complete` ResourceName is known to end in a suffix, otherwise only the `??????` pattern,
+// which cannot be part of a secret name, is separated from the name.
+fn parse_secret(arn: &ResourceName, complete: bool) -> Result<SecretsManagerResource, Error> {
+    expect_service(arn, SecretsManager)?;
+    let partition = partition_of(arn);
+    let region = region_of(arn)?;
+    let account = account_of(arn)?;
+    let invalid = || Error::InvalidResource(arn.resource.to_string());
+    let full_name = match arn.resource.split_once(':') {
+        Some(("secret", name)) if !name.contains(':') => name,
+        _ => return Err(invalid()),
+    };
+    let (name, suffix) = match split_suffix(full_name) {
+        (name, Some(suffix)) if complete || suffix == SUFFIX_PATTERN => (name, Some(suffix)),
+        (_, None) if complete => return Err(invalid()),
+        _ => (full_name, None),
+    };
+    Ok(SecretsManagerResource::Secret {
+        partition,
+        region,
+        account,
+        name: ResourceIdentifier::from_str(name).map_err(|_| invalid())?,
+        suffix: suffix
+            .map(Identifier::from_str)
+            .transpose()
+            .map_err(|_| invalid())?,
+    })
+}
+
+// A suffix is a hyphen followed by six alphanumeric characters, or the `??????` pattern.
+fn split_suffix(name: &str) -> (&str, Option<&str>) {
+    match name.rsplit_once('-') {
+        Some((name, suffix))
+            if !name.is_empty()
+                && suffix.len() == SUFFIX_LENGTH
+                && (suffix == SUFFIX_PATTERN
+                    || suffix.chars().all(|c| c.is_ascii_alphanumeric())) =>
+        {
+            (name, Some(suffix))
+        }
+        _ => (name, None),
+    }
+}
+
+fn check_secret_name(name: &ResourceIdentifier) -> Result<(), Error> {
+    check_name(name, 1..=512, |c| {
+        c.is_ascii_alphanumeric() || "/_+=.@-".contains(c)
+    })
+}
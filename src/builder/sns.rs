@@ -0,0 +1,433 @@
+/*!
+Provides a set of simple helper functions to make ResourceNames for the SNS service.
+
+These resource definitions ae take from the AWS
+[documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazonsns.html#amazonsns-resources-for-iam-policies)
+and the [SNS API reference](https://docs.aws.amazon.com/sns/latest/api/API_Subscribe.html).
+
+A subscription ResourceName is the topic ResourceName qualified with the subscription ID, and
+`topic_from` recovers the topic of a subscription.
+*/
+
+use crate::builder::{
+    account_of, check_name, expect_service, is_dotted_name_char, is_name_char, partition_of,
+    region_of, ArnBuilder, ServiceResource,
+};
+use crate::known::Service::SimpleNotification;
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the SNS ResourceNames constructed by the functions in this module.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum SnsResource {
+    /// `arn:${Partition}:sns:${Region}:${Account}:${TopicName}`
+    Topic {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The topic name, ending in `.fifo` for a FIFO topic.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:sns:${Region}:${Account}:${TopicName}:${SubscriptionId}`
+    Subscription {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The name of the subscribed topic.
+        topic_name: Identifier,
+        /// The subscription ID, a UUID.
+        subscription_id: Identifier,
+    },
+    /// `arn:${Partition}:sns:${Region}:${Account}:app/${Platform}/${ApplicationName}`
+    PlatformApplication {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The push notification platform, for example `APNS` or `GCM`.
+        platform: Identifier,
+        /// The platform application name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:sns:${Region}:${Account}:endpoint/${Platform}/${ApplicationName}/${EndpointId}`
+    PlatformEndpoint {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The push notification platform, for example `APNS` or `GCM`.
+        platform: Identifier,
+        /// The platform application name.
+        application_name: Identifier,
+        /// The endpoint ID, a UUID.
+        endpoint_id: Identifier,
+    },
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// `arn:${Partition}:sns:${Region}:${Account}:${TopicName}`
+///
+/// The topic name may contain up to 256 alphanumeric, `-`, or `_` characters; a FIFO topic name
+/// must also end in `.fifo`, which is included in the 256.
+///
+pub fn topic(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_topic_name(&name)?;
+    Ok(sns_arn(partition, region, account, name.into()))
+}
+
+///
+/// `arn:${Partition}:sns:${Region}:${Account}:${TopicName}:${SubscriptionId}`
+///
+pub fn subscription(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    topic_name: Identifier,
+    subscription_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_topic_name(&topic_name)?;
+    check_uuid(&subscription_id)?;
+    Ok(subscription_arn(
+        partition,
+        region,
+        account,
+        topic_name,
+        subscription_id,
+    ))
+}
+
+///
+/// `arn:${Partition}:sns:${Region}:${Account}:app/${Platform}/${ApplicationName}`
+///
+pub fn platform_application(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    platform: Identifier,
+    name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_platform(&platform)?;
+    check_application_name(&name)?;
+    Ok(sns_arn(
+        partition,
+        region,
+        account,
+        ResourceIdentifier::from_id_path(&[Identifier::new_unchecked("app"), platform, name]),
+    ))
+}
+
+///
+/// `arn:${Partition}:sns:${Region}:${Account}:endpoint/${Platform}/${ApplicationName}/${EndpointId}`
+///
+pub fn platform_endpoint(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    platform: Identifier,
+    application_name: Identifier,
+    endpoint_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_platform(&platform)?;
+    check_application_name(&application_name)?;
+    check_uuid(&endpoint_id)?;
+    Ok(platform_endpoint_arn(
+        partition,
+        region,
+        account,
+        platform,
+        application_name,
+        endpoint_id,
+    ))
+}
+
+///
+/// Return the topic ResourceName for a subscription ResourceName; a topic ResourceName is
+/// returned unchanged.
+///
+pub fn topic_from(arn: &ResourceName) -> Result<ResourceName, Error> {
+    match SnsResource::try_from(arn)? {
+        SnsResource::Topic { .. } => Ok(arn.clone()),
+        SnsResource::Subscription {
+            partition,
+            region,
+            account,
+            topic_name,
+            ..
+        } => Ok(sns_arn(partition, region, account, topic_name.into())),
+        other => Err(Error::ResourceMismatch {
+            expected: "subscription".to_string(),
+            found: other.resource_type_name().to_string(),
+        }),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const FIFO_SUFFIX: &str = ".fifo";
+
+const PLATFORMS: &[&str] = &[
+    "ADM",
+    "APNS",
+    "APNS_SANDBOX",
+    "APNS_VOIP",
+    "APNS_VOIP_SANDBOX",
+    "BAIDU",
+    "GCM",
+    "MACOS",
+    "MACOS_SANDBOX",
+    "MPNS",
+    "WNS",
+];
+
+impl SnsResource {
+    /// Returns `true` if this is a FIFO topic, or a subscription to one.
+    pub fn is_fifo(&self) -> bool {
+        match self {
+            SnsResource::Topic { name, .. } => name.ends_with(FIFO_SUFFIX),
+            SnsResource::Subscription { topic_name, .. } => topic_name.ends_with(FIFO_SUFFIX),
+            _ => false,
+        }
+    }
+}
+
+impl TryFrom<&ResourceName> for SnsResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        expect_service(arn, SimpleNotification)?;
+        let partition = partition_of(arn);
+        let region = region_of(arn)?;
+        let account = account_of(arn)?;
+        let invalid = || Error::InvalidResource(arn.resource.to_string());
+        let id = |s: &str, check: fn(&Identifier) -> Result<(), Error>| {
+            let id = Identifier::from_str(s).map_err(|_| invalid())?;
+            check(&id).map_err(|_| invalid())?;
+            Ok(id)
+        };
+        if arn.resource.contains('/') {
+            let parts: Vec<&str> = arn.resource.split('/').collect();
+            match parts.as_slice() {
+                ["app", platform, name] => Ok(SnsResource::PlatformApplication {
+                    partition,
+                    region,
+                    account,
+                    platform: id(platform, check_platform)?,
+                    name: id(name, check_application_name)?,
+                }),
+                ["endpoint", platform, application_name, endpoint_id] => {
+                    Ok(SnsResource::PlatformEndpoint {
+                        partition,
+                        region,
+                        account,
+                        platform: id(platform, check_platform)?,
+                        application_name: id(application_name, check_application_name)?,
+                        endpoint_id: id(endpoint_id, check_uuid)?,
+                    })
+                }
+                _ => Err(invalid()),
+            }
+        } else {
+            let parts: Vec<&str> = arn.resource.split(':').collect();
+            match parts.as_slice() {
+                [name] => Ok(SnsResource::Topic {
+                    partition,
+                    region,
+                    account,
+                    name: id(name, check_topic_name)?,
+                }),
+                [topic_name, subscription_id] => Ok(SnsResource::Subscription {
+                    partition,
+                    region,
+                    account,
+                    topic_name: id(topic_name, check_topic_name)?,
+                    subscription_id: id(subscription_id, check_uuid)?,
+                }),
+                _ => Err(invalid()),
+            }
+        }
+    }
+}
+
+impl From<&SnsResource> for ResourceName {
+    fn from(v: &SnsResource) -> Self {
+        match v.clone() {
+            SnsResource::Topic {
+                partition,
+                region,
+                account,
+                name,
+            } => sns_arn(partition, region, account, name.into()),
+            SnsResource::Subscription {
+                partition,
+                region,
+                account,
+                topic_name,
+                subscription_id,
+            } => subscription_arn(partition, region, account, topic_name, subscription_id),
+            SnsResource::PlatformApplication {
+                partition,
+                region,
+                account,
+                platform,
+                name,
+            } => sns_arn(
+                partition,
+                region,
+                account,
+                ResourceIdentifier::from_id_path(&[
+                    Identifier::new_unchecked("app"),
+                    platform,
+                    name,
+                ]),
+            ),
+            SnsResource::PlatformEndpoint {
+                partition,
+                region,
+                account,
+                platform,
+                application_name,
+                endpoint_id,
+            } => platform_endpoint_arn(
+                partition,
+                region,
+                account,
+                platform,
+                application_name,
+                endpoint_id,
+            ),
+        }
+    }
+}
+
+impl From<SnsResource> for ResourceName {
+    fn from(v: SnsResource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for SnsResource {
+    fn service(&self) -> Identifier {
+        SimpleNotification.into()
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            SnsResource::Topic { .. } => "topic",
+            SnsResource::Subscription { .. } => "subscription",
+            SnsResource::PlatformApplication { .. } => "app",
+            SnsResource::PlatformEndpoint { .. } => "endpoint",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn sns_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    resource: ResourceIdentifier,
+) -> ResourceName {
+    ArnBuilder::service_id(SimpleNotification.into())
+        .in_partition_id(partition)
+        .in_region_id(region)
+        .owned_by(account)
+        .is(resource)
+        .into()
+}
+
+fn subscription_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    topic_name: Identifier,
+    subscription_id: Identifier,
+) -> ResourceName {
+    sns_arn(
+        partition,
+        region,
+        account,
+        ResourceIdentifier::from_qualified_id(&[topic_name, subscription_id]),
+    )
+}
+
+fn platform_endpoint_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    platform: Identifier,
+    application_name: Identifier,
+    endpoint_id: Identifier,
+) -> ResourceName {
+    sns_arn(
+        partition,
+        region,
+        account,
+        ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("endpoint"),
+            platform,
+            application_name,
+            endpoint_id,
+        ]),
+    )
+}
+
+fn check_topic_name(name: &Identifier) -> Result<(), Error> {
+    match name.strip_suffix(FIFO_SUFFIX) {
+        Some(stem) => check_name(stem, 1..=(256 - FIFO_SUFFIX.len()), is_name_char)
+            .map_err(|_| Error::InvalidIdentifier(name.to_string())),
+        None => check_name(name, 1..=256, is_name_char),
+    }
+}
+
+fn check_application_name(name: &Identifier) -> Result<(), Error> {
+    check_name(name, 1..=256, is_dotted_name_char)
+}
+
+fn check_platform(platform: &Identifier) -> Result<(), Error> {
+    if PLATFORMS.contains(&platform.as_ref()) || platform.contains(['*', '?']) {
+        Ok(())
+    } else {
+        Err(Error::InvalidIdentifier(platform.to_string()))
+    }
+}
+
+fn check_uuid(id: &Identifier) -> Result<(), Error> {
+    check_name(id, 36..=36, |c| c.is_ascii_hexdigit() || c == '-')
+}
@@ -0,0 +1,236 @@
+/*!
+Provides a set of simple helper functions to make ResourceNames for the SQS service.
+
+These resource definitions ae take from the AWS
+[documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazonsqs.html#amazonsqs-resources-for-iam-policies).
+
+The SQS API identifies a queue by its URL, of the form
+`https://sqs.${Region}.amazonaws.com/${Account}/${QueueName}`, while IAM policies, event source
+mappings, and SNS subscriptions use the queue ResourceName; `from_url` and `to_url` convert
+between the two. The legacy `queue.amazonaws.com` and `${Region}.queue.amazonaws.com` endpoints,
+and the `amazonaws.com.cn` endpoints, are also recognized.
+*/
+
+use crate::builder::{
+    account_of, check_name, domain_of, expect_service, is_name_char, partition_of,
+    partition_of_endpoint, region_of, ArnBuilder, ServiceResource, DOMAIN, DOMAIN_CHINA,
+};
+use crate::known::Service::SimpleQueue;
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the SQS ResourceNames constructed by the functions in this module.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum SqsResource {
+    /// `arn:${Partition}:sqs:${Region}:${Account}:${QueueName}`
+    Queue {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The queue name, ending in `.fifo` for a FIFO queue.
+        name: Identifier,
+    },
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// `arn:${Partition}:sqs:${Region}:${Account}:${QueueName}`
+///
+/// The queue name may contain up to 80 alphanumeric, `-`, or `_` characters; a FIFO queue name
+/// must also end in `.fifo`, which is included in the 80.
+///
+pub fn queue(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_queue_name(&name)?;
+    Ok(sqs_arn(partition, region, account, name))
+}
+
+///
+/// Convert a queue URL, of the form `https://sqs.${Region}.amazonaws.com/${Account}/${QueueName}`,
+/// into a queue ResourceName. The legacy `queue.amazonaws.com` endpoint is in `us-east-1`. The
+/// partition is taken from the domain, `amazonaws.com.cn` for `aws-cn`, and from `us-gov-` regions
+/// for `aws-us-gov`.
+///
+pub fn from_url(url: &str) -> Result<ResourceName, Error> {
+    let invalid = || Error::InvalidUrl(url.to_string());
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .ok_or_else(invalid)?;
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let (host, path) = rest.split_once('/').ok_or_else(invalid)?;
+    let host = host.split(':').next().unwrap_or_default();
+    let (host, china) = match host.strip_suffix(DOMAIN_CHINA) {
+        Some(host) => (host, true),
+        None => (host.strip_suffix(DOMAIN).ok_or_else(invalid)?, false),
+    };
+    let labels: Vec<&str> = host.split('.').collect();
+    let region = match labels.as_slice() {
+        ["sqs", region] | [region, "queue"] if !ENDPOINT_LABELS.contains(region) => *region,
+        ["queue"] if !china => LEGACY_REGION,
+        _ => return Err(invalid()),
+    };
+    let (account, name) = match path.trim_end_matches('/').split('/').collect::<Vec<_>>()[..] {
+        [account, name] => (account, name),
+        _ => return Err(invalid()),
+    };
+    let name = Identifier::from_str(name).map_err(|_| invalid())?;
+    check_queue_name(&name).map_err(|_| invalid())?;
+    Ok(sqs_arn(
+        partition_of_endpoint(china, Some(region)),
+        Identifier::from_str(region).map_err(|_| invalid())?,
+        AccountIdentifier::from_str(account).map_err(|_| invalid())?,
+        name,
+    ))
+}
+
+///
+/// Convert a queue ResourceName into a queue URL, of the form
+/// `https://sqs.${Region}.amazonaws.com/${Account}/${QueueName}`. A URL names a single queue, so
+/// the region, account, and queue name must not contain wildcards.
+///
+pub fn to_url(arn: &ResourceName) -> Result<String, Error> {
+    let SqsResource::Queue {
+        partition,
+        region,
+        account,
+        name,
+    } = SqsResource::try_from(arn)?;
+    check_queue_name(&name)?;
+    if region.has_wildcards() {
+        return Err(Error::RegionWildcardNotAllowed);
+    }
+    if account.has_wildcards() {
+        return Err(Error::AccountIdWildcardNotAllowed);
+    }
+    if name.has_wildcards() {
+        return Err(Error::ResourceWildcardNotAllowed);
+    }
+    Ok(format!(
+        "https://sqs.{}{}/{}/{}",
+        region,
+        domain_of(&partition)?,
+        account,
+        name
+    ))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const FIFO_SUFFIX: &str = ".fifo";
+
+const LEGACY_REGION: &str = "us-east-1";
+
+const ENDPOINT_LABELS: &[&str] = &["sqs", "queue"];
+
+impl SqsResource {
+    /// Returns `true` if this is a FIFO queue, that is its name ends in `.fifo`.
+    pub fn is_fifo(&self) -> bool {
+        match self {
+            SqsResource::Queue { name, .. } => name.ends_with(FIFO_SUFFIX),
+        }
+    }
+}
+
+impl TryFrom<&ResourceName> for SqsResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        expect_service(arn, SimpleQueue)?;
+        let partition = partition_of(arn);
+        let region = region_of(arn)?;
+        let account = account_of(arn)?;
+        if arn.resource.contains(['/', ':']) {
+            return Err(Error::InvalidResource(arn.resource.to_string()));
+        }
+        Ok(SqsResource::Queue {
+            partition,
+            region,
+            account,
+            name: Identifier::from_str(&arn.resource)
+                .map_err(|_| Error::InvalidResource(arn.resource.to_string()))?,
+        })
+    }
+}
+
+impl From<&SqsResource> for ResourceName {
+    fn from(v: &SqsResource) -> Self {
+        match v.clone() {
+            SqsResource::Queue {
+                partition,
+                region,
+                account,
+                name,
+            } => sqs_arn(partition, region, account, name),
+        }
+    }
+}
+
+impl From<SqsResource> for ResourceName {
+    fn from(v: SqsResource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for SqsResource {
+    fn service(&self) -> Identifier {
+        SimpleQueue.into()
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            SqsResource::Queue { .. } => "queue",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn sqs_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: Identifier,
+) -> ResourceName {
+    ArnBuilder::service_id(SimpleQueue.into())
+        .in_partition_id(partition)
+        .in_region_id(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from(name))
+        .into()
+}
+
+fn check_queue_name(name: &Identifier) -> Result<(), Error> {
+    match name.strip_suffix(FIFO_SUFFIX) {
+        Some(stem) => check_name(stem, 1..=(80 - FIFO_SUFFIX.len()), is_name_char)
+            .map_err(|_| Error::InvalidIdentifier(name.to_string())),
+        None => check_name(name, 1..=80, is_name_char),
+    }
+}
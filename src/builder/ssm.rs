@@ -0,0 +1,462 @@
+/*!
+Provides a set of simple helper functions to make ResourceNames for the SSM service.
+
+These resource definitions ae take from the AWS
+[documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_awssystemsmanager.html#awssystemsmanager-resources-for-iam-policies).
+
+A hierarchical parameter name, such as `/app/prod/db/password`, appears in the ResourceName
+without its leading slash, `parameter/app/prod/db/password`. The `parameter` function accepts the
+name with or without the leading slash, and `SsmResource::parameter_name` restores it. Public
+parameters, under `/aws/service/`, and documents owned by AWS have no account component.
+*/
+
+use crate::builder::{
+    check_name, expect_service, is_dotted_name_char, partition_of, region_of, ArnBuilder,
+    ServiceResource,
+};
+use crate::known::Service::SimpleSystemsManager;
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the SSM ResourceNames constructed by the functions in this module.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum SsmResource {
+    /// `arn:${Partition}:ssm:${Region}:${Account}:parameter/${ParameterNameWithoutLeadingSlash}`
+    Parameter {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component, `None` for public parameters.
+        account: Option<AccountIdentifier>,
+        /// The parameter name, without a leading slash.
+        name: ResourceIdentifier,
+    },
+    /// `arn:${Partition}:ssm:${Region}:${Account}:document/${DocumentName}`
+    Document {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component, `None` for documents owned by AWS.
+        account: Option<AccountIdentifier>,
+        /// The document name.
+        name: Identifier,
+    },
+    /// `arn:${Partition}:ssm:${Region}:${Account}:automation-definition/${AutomationDefinitionName}[:${VersionId}]`
+    AutomationDefinition {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The automation definition (runbook) name.
+        name: Identifier,
+        /// The optional version, a number, `$DEFAULT`, or `$LATEST`.
+        version: Option<Identifier>,
+    },
+    /// `arn:${Partition}:ssm:${Region}:${Account}:maintenancewindow/${WindowId}`
+    MaintenanceWindow {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The maintenance window ID, `mw-` followed by 17 hex digits.
+        window_id: Identifier,
+    },
+    /// `arn:${Partition}:ssm:${Region}:${Account}:managed-instance/${InstanceId}`
+    ManagedInstance {
+        /// The partition component.
+        partition: Identifier,
+        /// The region component.
+        region: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The managed instance ID, `mi-` followed by 17 hex digits.
+        instance_id: Identifier,
+    },
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// `arn:${Partition}:ssm:${Region}:${Account}:parameter/${ParameterNameWithoutLeadingSlash}`
+///
+/// The parameter name may be given with or without its leading slash. A parameter with no account
+/// must be a public parameter, under `/aws/`, and a parameter with an account may not start with
+/// `aws` or `ssm`.
+///
+pub fn parameter(
+    partition: Identifier,
+    region: Identifier,
+    account: Option<AccountIdentifier>,
+    name: ResourceIdentifier,
+) -> Result<ResourceName, Error> {
+    let name = match name.strip_prefix(PATH_SEPARATOR) {
+        Some(name) => ResourceIdentifier::from_str(name)?,
+        None => name,
+    };
+    check_parameter_name(&name, account.is_some())?;
+    Ok(ssm_arn(
+        partition,
+        region,
+        account,
+        ResourceIdentifier::from_path(&[ResourceIdentifier::new_unchecked("parameter"), name]),
+    ))
+}
+
+///
+/// `arn:${Partition}:ssm:${Region}:${Account}:document/${DocumentName}`
+///
+pub fn document(
+    partition: Identifier,
+    region: Identifier,
+    account: Option<AccountIdentifier>,
+    name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&name, 3..=128, is_dotted_name_char)?;
+    Ok(typed_arn(partition, region, account, "document", name))
+}
+
+///
+/// `arn:${Partition}:ssm:${Region}:${Account}:automation-definition/${AutomationDefinitionName}[:${VersionId}]`
+///
+pub fn automation_definition(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: Identifier,
+    version: Option<Identifier>,
+) -> Result<ResourceName, Error> {
+    check_name(&name, 3..=128, is_dotted_name_char)?;
+    if let Some(version) = &version {
+        check_version(version)?;
+    }
+    Ok(automation_definition_arn(
+        partition, region, account, name, version,
+    ))
+}
+
+///
+/// `arn:${Partition}:ssm:${Region}:${Account}:maintenancewindow/${WindowId}`
+///
+pub fn maintenance_window(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    window_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_id(&window_id, PREFIX_MAINTENANCE_WINDOW)?;
+    Ok(typed_arn(
+        partition,
+        region,
+        Some(account),
+        "maintenancewindow",
+        window_id,
+    ))
+}
+
+///
+/// `arn:${Partition}:ssm:${Region}:${Account}:managed-instance/${InstanceId}`
+///
+pub fn managed_instance(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    instance_id: Identifier,
+) -> Result<ResourceName, Error> {
+    check_id(&instance_id, PREFIX_MANAGED_INSTANCE)?;
+    Ok(typed_arn(
+        partition,
+        region,
+        Some(account),
+        "managed-instance",
+        instance_id,
+    ))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const PATH_SEPARATOR: char = '/';
+
+const PUBLIC_PARAMETER_PREFIX: &str = "aws/";
+
+const RESERVED_PARAMETER_PREFIXES: &[&str] = &["aws", "ssm"];
+
+const MAX_PARAMETER_LEVELS: usize = 15;
+
+const PREFIX_MAINTENANCE_WINDOW: &str = "mw-";
+const PREFIX_MANAGED_INSTANCE: &str = "mi-";
+
+impl SsmResource {
+    /// Returns the name of a parameter as used by the SSM API, with a leading slash if the name
+    /// is hierarchical, or `None` if this is not a parameter.
+    pub fn parameter_name(&self) -> Option<String> {
+        match self {
+            SsmResource::Parameter { name, .. } if name.contains(PATH_SEPARATOR) => {
+                Some(format!("{}{}", PATH_SEPARATOR, name))
+            }
+            SsmResource::Parameter { name, .. } => Some(name.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<&ResourceName> for SsmResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        expect_service(arn, SimpleSystemsManager)?;
+        let partition = partition_of(arn);
+        let region = region_of(arn)?;
+        let account = arn.account_id.clone().filter(|a| !a.is_empty());
+        let invalid = || Error::InvalidResource(arn.resource.to_string());
+        let id = |s: &str| Identifier::from_str(s).map_err(|_| invalid());
+        let required = || account.clone().ok_or(Error::MissingAccountId);
+        let (resource_type, rest) = arn
+            .resource
+            .split_once(PATH_SEPARATOR)
+            .ok_or_else(invalid)?;
+        match resource_type {
+            "parameter" if !rest.starts_with(PATH_SEPARATOR) => Ok(SsmResource::Parameter {
+                partition,
+                region,
+                account,
+                name: ResourceIdentifier::from_str(rest).map_err(|_| invalid())?,
+            }),
+            "document" => Ok(SsmResource::Document {
+                partition,
+                region,
+                account,
+                name: id(rest)?,
+            }),
+            "automation-definition" => {
+                let (name, version) = match rest.split_once(':') {
+                    Some((name, version)) => (name, Some(id(version)?)),
+                    None => (rest, None),
+                };
+                Ok(SsmResource::AutomationDefinition {
+                    partition,
+                    region,
+                    account: required()?,
+                    name: id(name)?,
+                    version,
+                })
+            }
+            "maintenancewindow" => Ok(SsmResource::MaintenanceWindow {
+                partition,
+                region,
+                account: required()?,
+                window_id: id(rest)?,
+            }),
+            "managed-instance" => Ok(SsmResource::ManagedInstance {
+                partition,
+                region,
+                account: required()?,
+                instance_id: id(rest)?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl From<&SsmResource> for ResourceName {
+    fn from(v: &SsmResource) -> Self {
+        match v.clone() {
+            SsmResource::Parameter {
+                partition,
+                region,
+                account,
+                name,
+            } => ssm_arn(
+                partition,
+                region,
+                account,
+                ResourceIdentifier::from_path(&[
+                    ResourceIdentifier::new_unchecked("parameter"),
+                    name,
+                ]),
+            ),
+            SsmResource::Document {
+                partition,
+                region,
+                account,
+                name,
+            } => typed_arn(partition, region, account, "document", name),
+            SsmResource::AutomationDefinition {
+                partition,
+                region,
+                account,
+                name,
+                version,
+            } => automation_definition_arn(partition, region, account, name, version),
+            SsmResource::MaintenanceWindow {
+                partition,
+                region,
+                account,
+                window_id,
+            } => typed_arn(
+                partition,
+                region,
+                Some(account),
+                "maintenancewindow",
+                window_id,
+            ),
+            SsmResource::ManagedInstance {
+                partition,
+                region,
+                account,
+                instance_id,
+            } => typed_arn(
+                partition,
+                region,
+                Some(account),
+                "managed-instance",
+                instance_id,
+            ),
+        }
+    }
+}
+
+impl From<SsmResource> for ResourceName {
+    fn from(v: SsmResource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for SsmResource {
+    fn service(&self) -> Identifier {
+        SimpleSystemsManager.into()
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            SsmResource::Parameter { .. } => "parameter",
+            SsmResource::Document { .. } => "document",
+            SsmResource::AutomationDefinition { .. } => "automation-definition",
+            SsmResource::MaintenanceWindow { .. } => "maintenancewindow",
+            SsmResource::ManagedInstance { .. } => "managed-instance",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn ssm_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: Option<AccountIdentifier>,
+    resource: ResourceIdentifier,
+) -> ResourceName {
+    let mut builder = ArnBuilder::service_id(SimpleSystemsManager.into());
+    let _ = builder.in_partition_id(partition).in_region_id(region);
+    if let Some(account) = account {
+        let _ = builder.owned_by(account);
+    }
+    builder.is(resource).into()
+}
+
+fn typed_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: Option<AccountIdentifier>,
+    resource_type: &str,
+    id: Identifier,
+) -> ResourceName {
+    ssm_arn(
+        partition,
+        region,
+        account,
+        ResourceIdentifier::from_id_path(&[Identifier::new_unchecked(resource_type), id]),
+    )
+}
+
+fn automation_definition_arn(
+    partition: Identifier,
+    region: Identifier,
+    account: AccountIdentifier,
+    name: Identifier,
+    version: Option<Identifier>,
+) -> ResourceName {
+    let name = match version {
+        Some(version) => ResourceIdentifier::from_qualified_id(&[name, version]),
+        None => name.into(),
+    };
+    ssm_arn(
+        partition,
+        region,
+        Some(account),
+        ResourceIdentifier::from_path(&[
+            ResourceIdentifier::new_unchecked("automation-definition"),
+            name,
+        ]),
+    )
+}
+
+fn check_parameter_name(name: &ResourceIdentifier, has_account: bool) -> Result<(), Error> {
+    check_name(name, 1..=1011, |c| {
+        c.is_ascii_alphanumeric() || "_.-/".contains(c)
+    })?;
+    let levels: Vec<&str> = name.split(PATH_SEPARATOR).collect();
+    if levels.len() > MAX_PARAMETER_LEVELS || levels.iter().any(|l| l.is_empty()) {
+        return Err(Error::InvalidIdentifier(name.to_string()));
+    }
+    if !has_account {
+        if name.starts_with(PUBLIC_PARAMETER_PREFIX) {
+            Ok(())
+        } else {
+            Err(Error::MissingAccountId)
+        }
+    } else if RESERVED_PARAMETER_PREFIXES
+        .iter()
+        .any(|prefix| levels[0].to_ascii_lowercase().starts_with(prefix))
+    {
+        Err(Error::InvalidIdentifier(name.to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_version(version: &Identifier) -> Result<(), Error> {
+    if matches!(version.as_ref(), "$DEFAULT" | "$LATEST")
+        || version.contains(['*', '?'])
+        || version.chars().all(|c| c.is_ascii_digit())
+    {
+        Ok(())
+    } else {
+        Err(Error::InvalidIdentifier(version.to_string()))
+    }
+}
+
+fn check_id(id: &Identifier, prefix: &str) -> Result<(), Error> {
+    match id.strip_prefix(prefix) {
+        Some(hex) => check_name(hex, 17..=17, |c| {
+            c.is_ascii_digit() || ('a'..='f').contains(&c)
+        })
+        .map_err(|_| Error::InvalidIdentifier(id.to_string())),
+        None if id.contains(['*', '?']) => Ok(()),
+        None => Err(Error::InvalidIdentifier(id.to_string())),
+    }
+}
@@ -0,0 +1,257 @@
+/*!
+Provides a set of simple helper functions to make ResourceNames for the STS service.
+
+These resource definitions ae take from the AWS
+[documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_identifiers.html#identifiers-arns).
+
+An assumed-role ResourceName, as returned by `GetCallerIdentity` or recorded by CloudTrail, names
+the role but not its IAM path, so `role_from` takes a `RolePathResolver` to find the path of the
+underlying IAM role.
+*/
+
+use crate::builder::{
+    account_of, check_name, expect_service, iam, is_iam_name_char, partition_of, ArnBuilder,
+    ServiceResource,
+};
+use crate::known::Service::SecurityToken;
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The typed form of the STS ResourceNames constructed by the functions in this module.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum StsResource {
+    /// `arn:${Partition}:sts::${Account}:assumed-role/${RoleName}/${RoleSessionName}`
+    AssumedRole {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The name of the assumed role, without its IAM path.
+        role_name: Identifier,
+        /// The role session name.
+        session_name: Identifier,
+    },
+    /// `arn:${Partition}:sts::${Account}:federated-user/${UserName}`
+    FederatedUser {
+        /// The partition component.
+        partition: Identifier,
+        /// The account component.
+        account: AccountIdentifier,
+        /// The federated user name.
+        user_name: Identifier,
+    },
+}
+
+///
+/// Finds the IAM path of a role from its account and name, used by `role_from` to construct the
+/// IAM role ResourceName for an assumed role. The path is returned as its elements, an empty
+/// `Vec` represents the root path `/`.
+///
+/// This is implemented for any closure of the form
+/// `Fn(&AccountIdentifier, &Identifier) -> Result<Vec<Identifier>, Error>`, so a resolver that
+/// assumes every role is at the root path is simply `|_, _| Ok(vec![])`.
+///
+pub trait RolePathResolver {
+    /// Return the IAM path elements for the role `role_name` in the account `account`.
+    fn resolve_role_path(
+        &self,
+        account: &AccountIdentifier,
+        role_name: &Identifier,
+    ) -> Result<Vec<Identifier>, Error>;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// `arn:${Partition}:sts::${Account}:assumed-role/${RoleName}/${RoleSessionName}`
+///
+pub fn assumed_role(
+    partition: Identifier,
+    account: AccountIdentifier,
+    role_name: Identifier,
+    session_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&role_name, 1..=64, is_iam_name_char)?;
+    check_name(&session_name, 2..=64, is_iam_name_char)?;
+    Ok(sts_arn(
+        partition,
+        account,
+        &[
+            Identifier::new_unchecked("assumed-role"),
+            role_name,
+            session_name,
+        ],
+    ))
+}
+
+///
+/// `arn:${Partition}:sts::${Account}:federated-user/${UserName}`
+///
+pub fn federated_user(
+    partition: Identifier,
+    account: AccountIdentifier,
+    user_name: Identifier,
+) -> Result<ResourceName, Error> {
+    check_name(&user_name, 2..=32, is_iam_name_char)?;
+    Ok(sts_arn(
+        partition,
+        account,
+        &[Identifier::new_unchecked("federated-user"), user_name],
+    ))
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:role/${RoleNameWithPath}`
+///
+/// Construct the IAM role ResourceName for an assumed-role ResourceName, using `resolver` to find
+/// the role's path. Returns `Error::ResourceMismatch` if `assumed_role` is not an STS assumed-role
+/// ResourceName.
+///
+pub fn role_from<R>(assumed_role: &ResourceName, resolver: &R) -> Result<ResourceName, Error>
+where
+    R: RolePathResolver + ?Sized,
+{
+    match StsResource::try_from(assumed_role)? {
+        StsResource::AssumedRole {
+            partition,
+            account,
+            role_name,
+            ..
+        } => {
+            let path = resolver.resolve_role_path(&account, &role_name)?;
+            iam::role_with_path(partition, account, &path, role_name)
+        }
+        other => Err(Error::ResourceMismatch {
+            expected: "assumed-role".to_string(),
+            found: other.resource_type_name().to_string(),
+        }),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<F> RolePathResolver for F
+where
+    F: Fn(&AccountIdentifier, &Identifier) -> Result<Vec<Identifier>, Error>,
+{
+    fn resolve_role_path(
+        &self,
+        account: &AccountIdentifier,
+        role_name: &Identifier,
+    ) -> Result<Vec<Identifier>, Error> {
+        self(account, role_name)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl TryFrom<&ResourceName> for StsResource {
+    type Error = Error;
+
+    fn try_from(arn: &ResourceName) -> Result<Self, Self::Error> {
+        expect_service(arn, SecurityToken)?;
+        let partition = partition_of(arn);
+        let account = account_of(arn)?;
+        if arn.region.as_ref().is_some_and(|r| !r.is_empty()) {
+            return Err(Error::RegionNotAllowed);
+        }
+        let invalid = || Error::InvalidResource(arn.resource.to_string());
+        let parts: Vec<&str> = arn.resource.split('/').collect();
+        match parts.as_slice() {
+            ["assumed-role", role_name, session_name] => Ok(StsResource::AssumedRole {
+                partition,
+                account,
+                role_name: Identifier::from_str(role_name).map_err(|_| invalid())?,
+                session_name: Identifier::from_str(session_name).map_err(|_| invalid())?,
+            }),
+            ["federated-user", user_name] => Ok(StsResource::FederatedUser {
+                partition,
+                account,
+                user_name: Identifier::from_str(user_name).map_err(|_| invalid())?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl From<&StsResource> for ResourceName {
+    fn from(v: &StsResource) -> Self {
+        match v.clone() {
+            StsResource::AssumedRole {
+                partition,
+                account,
+                role_name,
+                session_name,
+            } => sts_arn(
+                partition,
+                account,
+                &[
+                    Identifier::new_unchecked("assumed-role"),
+                    role_name,
+                    session_name,
+                ],
+            ),
+            StsResource::FederatedUser {
+                partition,
+                account,
+                user_name,
+            } => sts_arn(
+                partition,
+                account,
+                &[Identifier::new_unchecked("federated-user"), user_name],
+            ),
+        }
+    }
+}
+
+impl From<StsResource> for ResourceName {
+    fn from(v: StsResource) -> Self {
+        ResourceName::from(&v)
+    }
+}
+
+impl ServiceResource for StsResource {
+    fn service(&self) -> Identifier {
+        SecurityToken.into()
+    }
+
+    fn resource_type_name(&self) -> &str {
+        match self {
+            StsResource::AssumedRole { .. } => "assumed-role",
+            StsResource::FederatedUser { .. } => "federated-user",
+        }
+    }
+
+    fn to_arn(&self) -> ResourceName {
+        self.into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn sts_arn(
+    partition: Identifier,
+    account: AccountIdentifier,
+    resource: &[Identifier],
+) -> ResourceName {
+    ArnBuilder::service_id(SecurityToken.into())
+        .in_partition_id(partition)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(resource))
+        .into()
+}
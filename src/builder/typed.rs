@@ -0,0 +1,381 @@
+/*!
+Provides a *typestate* variant of `ArnBuilder` where the rules for a service's ResourceNames are
+checked by the compiler rather than at runtime.
+
+The builder is parameterized by a service marker type, such as `Lambda` or `S3`, which implements
+`ServiceType`. The marker declares whether the region and account components of its ResourceNames
+are `Required`, `Optional`, or `NotAllowed`; the builder then tracks which components have been
+set, and `build()` is only available once the resource, and any required components, have been
+provided. Methods to set components the service does not allow are simply not available.
+
+# Example
+
+```rust
+use aws_arn::builder::typed::{ArnBuilder, Lambda};
+use aws_arn::known::Region;
+use aws_arn::{AccountIdentifier, ResourceIdentifier, ResourceName};
+use std::str::FromStr;
+
+let arn: ResourceName = ArnBuilder::service(Lambda)
+    .resource(ResourceIdentifier::from_str("function:my-function").unwrap())
+    .in_region(Region::UsEast2)
+    .owned_by(AccountIdentifier::from_str("123456789012").unwrap())
+    .build();
+assert_eq!(
+    arn.to_string(),
+    "arn:aws:lambda:us-east-2:123456789012:function:my-function"
+);
+```
+
+Leaving out the account for a Lambda ResourceName will not compile, as `build()` is not defined
+until the account has been set.
+
+```rust,compile_fail
+use aws_arn::builder::typed::{ArnBuilder, Lambda};
+use aws_arn::known::Region;
+use aws_arn::ResourceIdentifier;
+use std::str::FromStr;
+
+let arn = ArnBuilder::service(Lambda)
+    .resource(ResourceIdentifier::from_str("function:my-function").unwrap())
+    .in_region(Region::UsEast2)
+    .build();
+```
+
+Similarly, S3 bucket ResourceNames have no region and so `in_region` is not available.
+
+```rust,compile_fail
+use aws_arn::builder::typed::{ArnBuilder, S3};
+use aws_arn::known::Region;
+use aws_arn::ResourceIdentifier;
+use std::str::FromStr;
+
+let arn = ArnBuilder::service(S3)
+    .resource(ResourceIdentifier::from_str("my-bucket").unwrap())
+    .in_region(Region::UsEast2)
+    .build();
+```
+*/
+
+use crate::known::{Partition, Region, Service};
+use crate::{AccountIdentifier, Identifier, ResourceIdentifier, ResourceName};
+use std::marker::PhantomData;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implemented by the marker types that describe whether a component must, may, or must not be
+/// present in a ResourceName.
+///
+pub trait Requirement {}
+
+///
+/// Implemented by the requirement markers that allow a component to be set, i.e. `Required`
+/// and `Optional`.
+///
+pub trait Allowed: Requirement {}
+
+///
+/// The component must be present in the ResourceName.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Required;
+
+///
+/// The component may be present in the ResourceName.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Optional;
+
+///
+/// The component must not be present in the ResourceName.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NotAllowed;
+
+///
+/// Implemented by the builder state markers `Set` and `Unset` for each `Requirement` they satisfy.
+///
+pub trait Satisfies<R: Requirement> {}
+
+///
+/// Builder state: the component has been set.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Set;
+
+///
+/// Builder state: the component has not been set.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Unset;
+
+///
+/// Implemented by per-service marker types to describe the shape of the service's ResourceNames.
+///
+pub trait ServiceType {
+    /// Whether the region component is required, optional, or not allowed.
+    type Region: Requirement;
+
+    /// Whether the account component is required, optional, or not allowed.
+    type Account: Requirement;
+
+    /// The service identifier used in the ResourceName.
+    fn service() -> Service;
+}
+
+///
+/// Service marker for Cognito Identity, region and account are required.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CognitoIdentity;
+
+///
+/// Service marker for IAM, region is not allowed and account is required.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Iam;
+
+///
+/// Service marker for Lambda, region and account are required.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Lambda;
+
+///
+/// Service marker for S3 buckets and objects, neither region nor account are allowed.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct S3;
+
+///
+/// Service marker for regional S3 resources such as jobs, region and account are required.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct S3Regional;
+
+///
+/// Typestate builder type for an AWS `ResourceName`.
+///
+/// The type parameters are the service marker `S`, and the `Set`/`Unset` state of the resource
+/// (`Res`), region (`R`), and account (`A`) components.
+///
+#[derive(Clone, Debug)]
+pub struct ArnBuilder<S, Res = Unset, R = Unset, A = Unset> {
+    partition: Option<Identifier>,
+    region: Option<Identifier>,
+    account_id: Option<AccountIdentifier>,
+    resource: Option<ResourceIdentifier>,
+    state: PhantomData<(S, Res, R, A)>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Requirement for Required {}
+impl Requirement for Optional {}
+impl Requirement for NotAllowed {}
+
+impl Allowed for Required {}
+impl Allowed for Optional {}
+
+impl Satisfies<Required> for Set {}
+impl Satisfies<Optional> for Set {}
+impl Satisfies<Optional> for Unset {}
+impl Satisfies<NotAllowed> for Unset {}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ServiceType for CognitoIdentity {
+    type Region = Required;
+    type Account = Required;
+
+    fn service() -> Service {
+        Service::CognitoIdentity
+    }
+}
+
+impl ServiceType for Iam {
+    type Region = NotAllowed;
+    type Account = Required;
+
+    fn service() -> Service {
+        Service::IdentityAccessManagement
+    }
+}
+
+impl ServiceType for Lambda {
+    type Region = Required;
+    type Account = Required;
+
+    fn service() -> Service {
+        Service::Lambda
+    }
+}
+
+impl ServiceType for S3 {
+    type Region = NotAllowed;
+    type Account = NotAllowed;
+
+    fn service() -> Service {
+        Service::S3
+    }
+}
+
+impl ServiceType for S3Regional {
+    type Region = Required;
+    type Account = Required;
+
+    fn service() -> Service {
+        Service::S3
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<S, Res, R, A> From<ArnBuilder<S, Res, R, A>> for ResourceName
+where
+    S: ServiceType,
+    Res: Satisfies<Required>,
+    R: Satisfies<S::Region>,
+    A: Satisfies<S::Account>,
+{
+    fn from(v: ArnBuilder<S, Res, R, A>) -> Self {
+        v.build()
+    }
+}
+
+impl<S> ArnBuilder<S>
+where
+    S: ServiceType,
+{
+    /// Construct an ResourceName for the service described by the marker `service`.
+    pub fn service(_service: S) -> Self {
+        Self {
+            partition: None,
+            region: None,
+            account_id: None,
+            resource: None,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<S, Res, R, A> ArnBuilder<S, Res, R, A>
+where
+    S: ServiceType,
+{
+    /// Set a specific `partition` for this ResourceName.
+    pub fn in_partition(self, partition: Partition) -> Self {
+        self.in_partition_id(partition.into())
+    }
+
+    /// Set a specific `partition` for this ResourceName.
+    pub fn in_partition_id(mut self, partition: Identifier) -> Self {
+        self.partition = Some(partition);
+        self
+    }
+
+    /// Set a specific `partition` for this ResourceName.
+    pub fn in_default_partition(self) -> Self {
+        self.in_partition(Partition::default())
+    }
+
+    fn into_state<Res2, R2, A2>(self) -> ArnBuilder<S, Res2, R2, A2> {
+        ArnBuilder {
+            partition: self.partition,
+            region: self.region,
+            account_id: self.account_id,
+            resource: self.resource,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<S, R, A> ArnBuilder<S, Unset, R, A>
+where
+    S: ServiceType,
+{
+    /// Set the `resource` for this ResourceName.
+    pub fn resource(mut self, resource: ResourceIdentifier) -> ArnBuilder<S, Set, R, A> {
+        self.resource = Some(resource);
+        self.into_state()
+    }
+
+    /// Set the `resource` for this ResourceName.
+    pub fn is(self, resource: ResourceIdentifier) -> ArnBuilder<S, Set, R, A> {
+        self.resource(resource)
+    }
+}
+
+impl<S, Res, A> ArnBuilder<S, Res, Unset, A>
+where
+    S: ServiceType,
+    S::Region: Allowed,
+{
+    /// Set a specific `region` for this ResourceName.
+    pub fn in_region(self, region: Region) -> ArnBuilder<S, Res, Set, A> {
+        self.in_region_id(region.into())
+    }
+
+    /// Set a specific `region` for this ResourceName.
+    pub fn in_region_id(mut self, region: Identifier) -> ArnBuilder<S, Res, Set, A> {
+        self.region = Some(region);
+        self.into_state()
+    }
+
+    /// Set a specific `region` for this ResourceName.
+    pub fn and_region(self, region: Region) -> ArnBuilder<S, Res, Set, A> {
+        self.in_region(region)
+    }
+
+    /// Set a specific `region` for this ResourceName.
+    pub fn and_region_id(self, region: Identifier) -> ArnBuilder<S, Res, Set, A> {
+        self.in_region_id(region)
+    }
+}
+
+impl<S, Res, R> ArnBuilder<S, Res, R, Unset>
+where
+    S: ServiceType,
+    S::Account: Allowed,
+{
+    /// Set a specific `account` for this ResourceName.
+    pub fn in_account(mut self, account: AccountIdentifier) -> ArnBuilder<S, Res, R, Set> {
+        self.account_id = Some(account);
+        self.into_state()
+    }
+
+    /// Set a specific `account` for this ResourceName.
+    pub fn and_account(self, account: AccountIdentifier) -> ArnBuilder<S, Res, R, Set> {
+        self.in_account(account)
+    }
+
+    /// Set a specific `account` for this ResourceName.
+    pub fn owned_by(self, account: AccountIdentifier) -> ArnBuilder<S, Res, R, Set> {
+        self.in_account(account)
+    }
+}
+
+impl<S, Res, R, A> ArnBuilder<S, Res, R, A>
+where
+    S: ServiceType,
+    Res: Satisfies<Required>,
+    R: Satisfies<S::Region>,
+    A: Satisfies<S::Account>,
+{
+    /// Return the completed ResourceName, this is only available once the resource and all
+    /// components required by the service have been set.
+    pub fn build(self) -> ResourceName {
+        ResourceName {
+            partition: self.partition,
+            service: S::service().into(),
+            region: self.region,
+            account_id: self.account_id,
+            resource: self.resource.unwrap_or_default(),
+        }
+    }
+}
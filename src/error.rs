@@ -43,12 +43,16 @@ pub enum Error {
     InvalidRegion,
     /// The particular resource type does not allow region wildcards.
     RegionWildcardNotAllowed,
+    /// The particular resource type does not allow a region.
+    RegionNotAllowed,
     /// Missing the account id component.
     MissingAccountId,
     /// The partition account id provided is not valid.
     InvalidAccountId(String),
     /// The particular resource type does not allow account wildcards.
     AccountIdWildcardNotAllowed,
+    /// The particular resource type does not allow an account id.
+    AccountIdNotAllowed,
     /// Missing the resource component.
     MissingResource,
     /// The partition resource provided is not valid, the name of the particular component
@@ -56,6 +60,16 @@ pub enum Error {
     InvalidResource(String),
     /// The particular resource type does not allow resource wildcards.
     ResourceWildcardNotAllowed,
+    /// The URI or URL provided is not a recognized form for the service, the value is included.
+    InvalidUrl(String),
+    /// The ResourceName provided is not for the expected service or resource type, the expected
+    /// and the actual values are included.
+    ResourceMismatch {
+        /// The service or resource type that was expected.
+        expected: String,
+        /// The service or resource type that was found.
+        found: String,
+    },
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -1,8 +1,16 @@
 /*!
 Provides enums that represent known values for ARN partition, region, and service identifiers.
+
+The `ManagedPolicy` and `ServiceLinkedRole` enums are generated from the vendored lists in the
+`data` directory, and provide the ResourceNames of AWS managed policies and well-known
+service-linked roles in any partition.
 */
 
-use crate::{Identifier, IdentifierLike};
+use crate::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -12,9 +20,10 @@ use crate::{Identifier, IdentifierLike};
 /// A list of known partition identifiers from
 /// [docs.aws](https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html).
 ///
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub enum Partition {
     /// Corresponds to the partition "aws": AWS regions
+    #[default]
     Aws,
 
     /// Corresponds to the partition "aws-cn": AWS China regions
@@ -669,6 +678,9 @@ pub enum Service {
     /// Corresponds to the service "pinpoint-sms-voice"
     PinpointSmsVoice,
 
+    /// Corresponds to the service "pipes"
+    EventBridgePipes,
+
     /// Corresponds to the service "polly"
     Polly,
 
@@ -726,9 +738,15 @@ pub enum Service {
     /// Corresponds to the service "s3control"
     S3Control,
 
-    /// Corresponds to the service "s3outposts"
+    /// Corresponds to the service "s3-object-lambda"
+    S3ObjectLambda,
+
+    /// Corresponds to the service "s3-outposts"
     S3Outposts,
 
+    /// Corresponds to the service "s3express"
+    S3Express,
+
     /// Corresponds to the service "sagemaker"
     SageMaker,
 
@@ -747,6 +765,9 @@ pub enum Service {
     /// Corresponds to the service "savingsplans"
     SavingsPlans,
 
+    /// Corresponds to the service "scheduler"
+    EventBridgeScheduler,
+
     /// Corresponds to the service "schemas"
     EventBridgeSchemaRegistry,
 
@@ -877,16 +898,28 @@ pub enum Service {
     XRay,
 }
 
-// ------------------------------------------------------------------------------------------------
-// Implementations
-// ------------------------------------------------------------------------------------------------
+///
+/// Describes whether a component, such as the region or account, must be present, may be present,
+/// or must not be present in the ResourceNames for a service.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Presence {
+    /// The component must be present.
+    Required,
 
-impl Default for Partition {
-    fn default() -> Self {
-        Self::Aws
-    }
+    /// The component may, or may not, be present depending on the resource type.
+    Optional,
+
+    /// The component must not be present.
+    NotAllowed,
 }
 
+include!(concat!(env!("OUT_DIR"), "/managed_policies.rs"));
+
+include!(concat!(env!("OUT_DIR"), "/service_linked_roles.rs"));
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
 // ------------------------------------------------------------------------------------------------
 
 impl From<Partition> for Identifier {
@@ -929,280 +962,545 @@ impl From<Region> for Identifier {
 
 // ------------------------------------------------------------------------------------------------
 
-impl From<Service> for Identifier {
-    fn from(s: Service) -> Self {
-        match s {
-            Service::AccessAnalyzer => Identifier::new_unchecked("accessanalyzer"),
-            Service::CertificateManager => Identifier::new_unchecked("acm"),
-            Service::CertificateManagerPrivateCa => Identifier::new_unchecked("acm-pca"),
-            Service::AlexaForBusiness => Identifier::new_unchecked("alexaforbusiness"),
-            Service::Prometheus => Identifier::new_unchecked("amp"),
-            Service::Amplify => Identifier::new_unchecked("amplify"),
-            Service::AmplifyBackend => Identifier::new_unchecked("amplifybackend"),
-            Service::ApiGateway => Identifier::new_unchecked("apigateway"),
-            Service::ApiGatewayManagementApi => {
-                Identifier::new_unchecked("apigatewaymanagementapi")
-            }
-            Service::ApiGatewayV2 => Identifier::new_unchecked("apigatewayv2"),
-            Service::AppConfig => Identifier::new_unchecked("appconfig"),
-            Service::AppFlow => Identifier::new_unchecked("appflow"),
-            Service::AppIntegrations => Identifier::new_unchecked("appintegrations"),
-            Service::ApplicationAutoscaling => Identifier::new_unchecked("application-autoscaling"),
-            Service::ApplicationInsights => Identifier::new_unchecked("application-insights"),
-            Service::AppMesh => Identifier::new_unchecked("appmesh"),
-            Service::AppStream => Identifier::new_unchecked("appstream"),
-            Service::AppSync => Identifier::new_unchecked("appsync"),
-            Service::Athena => Identifier::new_unchecked("athena"),
-            Service::AuditManager => Identifier::new_unchecked("auditmanager"),
-            Service::AutoScaling => Identifier::new_unchecked("autoscaling"),
-            Service::AutoScalingPlans => Identifier::new_unchecked("autoscaling-plans"),
-            Service::Backup => Identifier::new_unchecked("backup"),
-            Service::Batch => Identifier::new_unchecked("batch"),
-            Service::Braket => Identifier::new_unchecked("braket"),
-            Service::Budgets => Identifier::new_unchecked("budgets"),
-            Service::CostExplorer => Identifier::new_unchecked("ce"),
-            Service::Chime => Identifier::new_unchecked("chime"),
-            Service::Cloud9 => Identifier::new_unchecked("cloud9"),
-            Service::CloudDirectory => Identifier::new_unchecked("clouddirectory"),
-            Service::CloudFormation => Identifier::new_unchecked("cloudformation"),
-            Service::CloudHsm => Identifier::new_unchecked("cloudhsm"),
-            Service::CloudHsmV2 => Identifier::new_unchecked("cloudhsmv2"),
-            Service::CloudSearch => Identifier::new_unchecked("cloudsearch"),
-            Service::CloudSearchDomain => Identifier::new_unchecked("cloudsearchdomain"),
-            Service::CloudTrail => Identifier::new_unchecked("cloudtrail"),
-            Service::CloudWatch => Identifier::new_unchecked("cloudwatch"),
-            Service::CodeArtifact => Identifier::new_unchecked("codeartifact"),
-            Service::CodeBuild => Identifier::new_unchecked("codebuild"),
-            Service::CodeCommit => Identifier::new_unchecked("codecommit"),
-            Service::CodeDeploy => Identifier::new_unchecked("codedeploy"),
-            Service::CodeGuruReviewer => Identifier::new_unchecked("codeguru-reviewer"),
-            Service::CodeGuruProfiler => Identifier::new_unchecked("codeguruprofiler"),
-            Service::CodePipeline => Identifier::new_unchecked("codepipeline"),
-            Service::CodeStar => Identifier::new_unchecked("codestar"),
-            Service::CodeStarConnections => Identifier::new_unchecked("codestar-connections"),
-            Service::CodeStarNotifications => Identifier::new_unchecked("codestar-notifications"),
-            Service::CognitoIdentity => Identifier::new_unchecked("cognito-identity"),
-            Service::CognitoIdentityProvider => Identifier::new_unchecked("cognito-idp"),
-            Service::CognitoSync => Identifier::new_unchecked("cognito-sync"),
-            Service::Comprehend => Identifier::new_unchecked("comprehend"),
-            Service::ComprehendMedical => Identifier::new_unchecked("comprehendmedical"),
-            Service::ComputeOptimizer => Identifier::new_unchecked("compute-optimizer"),
-            Service::Config => Identifier::new_unchecked("config"),
-            Service::Connect => Identifier::new_unchecked("connect"),
-            Service::ConnectContactLens => Identifier::new_unchecked("connect-contact-lens"),
-            Service::ConnectParticipant => Identifier::new_unchecked("connectparticipant"),
-            Service::CostUsageReport => Identifier::new_unchecked("cur"),
-            Service::CustomerProfiles => Identifier::new_unchecked("customer-profiles"),
-            Service::GlueDataBrew => Identifier::new_unchecked("databrew"),
-            Service::DataExchange => Identifier::new_unchecked("dataexchange"),
-            Service::DataPipeline => Identifier::new_unchecked("datapipeline"),
-            Service::DataSync => Identifier::new_unchecked("datasync"),
-            Service::DynamoDbAccelerator => Identifier::new_unchecked("dax"),
-            Service::Detective => Identifier::new_unchecked("detective"),
-            Service::DeviceFarm => Identifier::new_unchecked("devicefarm"),
-            Service::DevOpsGuru => Identifier::new_unchecked("devops-guru"),
-            Service::DirectConnect => Identifier::new_unchecked("directconnect"),
-            Service::Discovery => Identifier::new_unchecked("discovery"),
-            Service::DataLifecycleManager => Identifier::new_unchecked("dlm"),
-            Service::DatabaseMigration => Identifier::new_unchecked("dms"),
-            Service::DocumentDb => Identifier::new_unchecked("docdb"),
-            Service::DynamoDb => Identifier::new_unchecked("dynamodb"),
-            Service::DynamoDbStreams => Identifier::new_unchecked("dynamodbstreams"),
-            Service::ElasticBlockStore => Identifier::new_unchecked("ebs"),
-            Service::Ec2 => Identifier::new_unchecked("ec2"),
-            Service::Ec2InstanceConnect => Identifier::new_unchecked("ec2-instance-connect"),
-            Service::Ec2ContainerRegistry => Identifier::new_unchecked("ecr"),
-            Service::Ec2containerRegistryPublic => Identifier::new_unchecked("ecr-public"),
-            Service::Ec2ContainerService => Identifier::new_unchecked("ecs"),
-            Service::ElasticFileSystem => Identifier::new_unchecked("efs"),
-            Service::ElasticKubernetes => Identifier::new_unchecked("eks"),
-            Service::ElasticInference => Identifier::new_unchecked("elastic-inference"),
-            Service::Elasticache => Identifier::new_unchecked("elasticache"),
-            Service::ElasticBeanstalk => Identifier::new_unchecked("elasticbeanstalk"),
-            Service::ElasticTranscoder => Identifier::new_unchecked("elastictranscoder"),
-            Service::ElasticLoadBalancing => Identifier::new_unchecked("elb"),
-            Service::ElasticLoadBalancingV2 => Identifier::new_unchecked("elbv2"),
-            Service::ElasticMapReduce => Identifier::new_unchecked("emr"),
-            Service::ElasticMapReduceContainers => Identifier::new_unchecked("emr-containers"),
-            Service::ElasticsearchService => Identifier::new_unchecked("es"),
-            Service::EventBridge => Identifier::new_unchecked("events"),
-            Service::Firehose => Identifier::new_unchecked("firehose"),
-            Service::FaultInjectionSimulator => Identifier::new_unchecked("fis"),
-            Service::FirewallManagementService => Identifier::new_unchecked("fms"),
-            Service::ForecastService => Identifier::new_unchecked("forecast"),
-            Service::ForecastQueryService => Identifier::new_unchecked("forecastquery"),
-            Service::FraudDetector => Identifier::new_unchecked("frauddetector"),
-            Service::Fsx => Identifier::new_unchecked("fsx"),
-            Service::GameLift => Identifier::new_unchecked("gamelift"),
-            Service::Glacier => Identifier::new_unchecked("glacier"),
-            Service::GlobalAccelerator => Identifier::new_unchecked("globalaccelerator"),
-            Service::Glue => Identifier::new_unchecked("glue"),
-            Service::Greengrass => Identifier::new_unchecked("greengrass"),
-            Service::GreengrassV2 => Identifier::new_unchecked("greengrassv2"),
-            Service::GroundStation => Identifier::new_unchecked("groundstation"),
-            Service::GuardDuty => Identifier::new_unchecked("guardduty"),
-            Service::Health => Identifier::new_unchecked("health"),
-            Service::HealthLake => Identifier::new_unchecked("healthlake"),
-            Service::Honeycode => Identifier::new_unchecked("honeycode"),
-            Service::IdentityAccessManagement => Identifier::new_unchecked("iam"),
-            Service::IdentityStore => Identifier::new_unchecked("identitystore"),
-            Service::ImageBuilder => Identifier::new_unchecked("imagebuilder"),
-            Service::ImportExport => Identifier::new_unchecked("importexport"),
-            Service::Inspector => Identifier::new_unchecked("inspector"),
-            Service::IoT => Identifier::new_unchecked("iot"),
-            Service::IoTData => Identifier::new_unchecked("iot-data"),
-            Service::IoTJobsData => Identifier::new_unchecked("iot-jobs-data"),
-            Service::IoT1clickDevices => Identifier::new_unchecked("iot1click-devices"),
-            Service::IoT1clickProjects => Identifier::new_unchecked("iot1click-projects"),
-            Service::IoTAnalytics => Identifier::new_unchecked("iotanalytics"),
-            Service::IoTDeviceAdvisor => Identifier::new_unchecked("iotdeviceadvisor"),
-            Service::IoTEvents => Identifier::new_unchecked("iotevents"),
-            Service::IoTEventsData => Identifier::new_unchecked("iotevents-data"),
-            Service::IoTFleetHub => Identifier::new_unchecked("iotfleethub"),
-            Service::IoTSecureTunneling => Identifier::new_unchecked("iotsecuretunneling"),
-            Service::IoTSitewise => Identifier::new_unchecked("iotsitewise"),
-            Service::IoTThingsGraph => Identifier::new_unchecked("iotthingsgraph"),
-            Service::IoTWireless => Identifier::new_unchecked("iotwireless"),
-            Service::InteractiveVideo => Identifier::new_unchecked("ivs"),
-            Service::Kafka => Identifier::new_unchecked("kafka"),
-            Service::Kendra => Identifier::new_unchecked("kendra"),
-            Service::Kinesis => Identifier::new_unchecked("kinesis"),
-            Service::KinesisVideoArchivedMedia => {
-                Identifier::new_unchecked("kinesis-video-archived-media")
+// The single table of service identifiers, from which both `From<Service> for Identifier` and
+// `FromStr for Service` are generated so that the two cannot drift apart.
+macro_rules! service_identifiers {
+    ($($variant:ident => $identifier:literal),* $(,)?) => {
+        impl From<Service> for Identifier {
+            fn from(s: Service) -> Self {
+                match s {
+                    $(Service::$variant => Identifier::new_unchecked($identifier),)*
+                }
             }
-            Service::KinesisVideoMedia => Identifier::new_unchecked("kinesis-video-media"),
-            Service::KinesisVideoSignaling => Identifier::new_unchecked("kinesis-video-signaling"),
-            Service::KinesisAnalytics => Identifier::new_unchecked("kinesisanalytics"),
-            Service::KinesisAnalyticsV2 => Identifier::new_unchecked("kinesisanalyticsv2"),
-            Service::KinesisVideo => Identifier::new_unchecked("kinesisvideo"),
-            Service::KeyManagement => Identifier::new_unchecked("kms"),
-            Service::LakeFormation => Identifier::new_unchecked("lakeformation"),
-            Service::Lambda => Identifier::new_unchecked("lambda"),
-            Service::LexModels => Identifier::new_unchecked("lex-models"),
-            Service::LexRuntime => Identifier::new_unchecked("lex-runtime"),
-            Service::LexV2Models => Identifier::new_unchecked("lexv2-models"),
-            Service::LexV2Runtime => Identifier::new_unchecked("lexv2-runtime"),
-            Service::LicenseManager => Identifier::new_unchecked("license-manager"),
-            Service::Lightsail => Identifier::new_unchecked("lightsail"),
-            Service::Location => Identifier::new_unchecked("location"),
-            Service::CloudWatchLogs => Identifier::new_unchecked("logs"),
-            Service::LookoutEquipment => Identifier::new_unchecked("lookoutequipment"),
-            Service::LookoutMetrics => Identifier::new_unchecked("lookoutmetrics"),
-            Service::LookoutVision => Identifier::new_unchecked("lookoutvision"),
-            Service::MachineLearning => Identifier::new_unchecked("machinelearning"),
-            Service::Macie => Identifier::new_unchecked("macie"),
-            Service::Macie2 => Identifier::new_unchecked("macie2"),
-            Service::ManagedBlockchain => Identifier::new_unchecked("managedblockchain"),
-            Service::MarketplaceCatalog => Identifier::new_unchecked("marketplace-catalog"),
-            Service::MarketplaceEntitlement => Identifier::new_unchecked("marketplace-entitlement"),
-            Service::MarketplaceCommerceAnalytics => {
-                Identifier::new_unchecked("marketplacecommerceanalytics")
-            }
-            Service::MediaConnect => Identifier::new_unchecked("mediaconnect"),
-            Service::MediaConvert => Identifier::new_unchecked("mediaconvert"),
-            Service::MediaLive => Identifier::new_unchecked("medialive"),
-            Service::MediaPackage => Identifier::new_unchecked("mediapackage"),
-            Service::MediaPackageVod => Identifier::new_unchecked("mediapackage-vod"),
-            Service::MediaStore => Identifier::new_unchecked("mediastore"),
-            Service::MediaStoreData => Identifier::new_unchecked("mediastore-data"),
-            Service::MediaTailor => Identifier::new_unchecked("mediatailor"),
-            Service::MarketplaceMetering => Identifier::new_unchecked("meteringmarketplace"),
-            Service::MigrationHub => Identifier::new_unchecked("mgh"),
-            Service::ApplicationMigration => Identifier::new_unchecked("mgn"),
-            Service::MigrationHubConfig => Identifier::new_unchecked("migrationhub-config"),
-            Service::Mobile => Identifier::new_unchecked("mobile"),
-            Service::Mq => Identifier::new_unchecked("mq"),
-            Service::MechanicalTurk => Identifier::new_unchecked("mturk"),
-            Service::ManagedWorkflowsForApacheAirflow => Identifier::new_unchecked("mwaa"),
-            Service::Neptune => Identifier::new_unchecked("neptune"),
-            Service::NetworkFirewall => Identifier::new_unchecked("network-firewall"),
-            Service::NetworkManager => Identifier::new_unchecked("networkmanager"),
-            Service::OpsWorks => Identifier::new_unchecked("opsworks"),
-            Service::OpsWorksCm => Identifier::new_unchecked("opsworkscm"),
-            Service::Organizations => Identifier::new_unchecked("organizations"),
-            Service::Outposts => Identifier::new_unchecked("outposts"),
-            Service::Personalize => Identifier::new_unchecked("personalize"),
-            Service::PersonalizeEvents => Identifier::new_unchecked("personalize-events"),
-            Service::PersonalizeRuntime => Identifier::new_unchecked("personalize-runtime"),
-            Service::PerformanceInsights => Identifier::new_unchecked("pi"),
-            Service::Pinpoint => Identifier::new_unchecked("pinpoint"),
-            Service::PinpointEmail => Identifier::new_unchecked("pinpoint-email"),
-            Service::PinpointSmsVoice => Identifier::new_unchecked("pinpoint-sms-voice"),
-            Service::Polly => Identifier::new_unchecked("polly"),
-            Service::Pricing => Identifier::new_unchecked("pricing"),
-            Service::Qldb => Identifier::new_unchecked("qldb"),
-            Service::QldbSession => Identifier::new_unchecked("qldb-session"),
-            Service::QuickSight => Identifier::new_unchecked("quicksight"),
-            Service::ResourceAccessManager => Identifier::new_unchecked("ram"),
-            Service::RelationalDatabaseService => Identifier::new_unchecked("rds"),
-            Service::RdsDataService => Identifier::new_unchecked("rds-data"),
-            Service::Redshift => Identifier::new_unchecked("redshift"),
-            Service::RedshiftDataApiService => Identifier::new_unchecked("redshift-data"),
-            Service::Rekognition => Identifier::new_unchecked("rekognition"),
-            Service::ResourceGroups => Identifier::new_unchecked("resource-groups"),
-            Service::ResourceGroupsTaggingApi => {
-                Identifier::new_unchecked("resourcegroupstaggingapi")
-            }
-            Service::RoboMaker => Identifier::new_unchecked("robomaker"),
-            Service::Route53 => Identifier::new_unchecked("route53"),
-            Service::Route53Domains => Identifier::new_unchecked("route53domains"),
-            Service::Route53Resolver => Identifier::new_unchecked("route53resolver"),
-            Service::S3 => Identifier::new_unchecked("s3"),
-            Service::S3Control => Identifier::new_unchecked("s3control"),
-            Service::S3Outposts => Identifier::new_unchecked("s3outposts"),
-            Service::SageMaker => Identifier::new_unchecked("sagemaker"),
-            Service::AugmentedAiRuntime => Identifier::new_unchecked("sagemaker-a2i-runtime"),
-            Service::SagemakerEdgeManager => Identifier::new_unchecked("sagemaker-edge"),
-            Service::SageMakerFeatureStoreRuntime => {
-                Identifier::new_unchecked("sagemaker-featurestore-runtime")
+        }
+
+        impl FromStr for Service {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($identifier => Ok(Service::$variant),)*
+                    _ => Err(Error::InvalidService),
+                }
             }
-            Service::SageMakerRuntime => Identifier::new_unchecked("sagemaker-runtime"),
-            Service::SavingsPlans => Identifier::new_unchecked("savingsplans"),
-            Service::EventBridgeSchemaRegistry => Identifier::new_unchecked("schemas"),
-            Service::SimpleDb => Identifier::new_unchecked("sdb"),
-            Service::SecretsManager => Identifier::new_unchecked("secretsmanager"),
-            Service::SecurityHub => Identifier::new_unchecked("securityhub"),
-            Service::ServerlessApplicationRepository => Identifier::new_unchecked("serverlessrepo"),
-            Service::ServiceQuotas => Identifier::new_unchecked("service-quotas"),
-            Service::ServiceCatalog => Identifier::new_unchecked("servicecatalog"),
-            Service::ServiceCatalogAppRegistry => {
-                Identifier::new_unchecked("servicecatalog-appregistry")
+        }
+    };
+}
+
+service_identifiers! {
+    AccessAnalyzer => "accessanalyzer",
+    CertificateManager => "acm",
+    CertificateManagerPrivateCa => "acm-pca",
+    AlexaForBusiness => "alexaforbusiness",
+    Prometheus => "amp",
+    Amplify => "amplify",
+    AmplifyBackend => "amplifybackend",
+    ApiGateway => "apigateway",
+    ApiGatewayManagementApi => "apigatewaymanagementapi",
+    ApiGatewayV2 => "apigatewayv2",
+    AppConfig => "appconfig",
+    AppFlow => "appflow",
+    AppIntegrations => "appintegrations",
+    ApplicationAutoscaling => "application-autoscaling",
+    ApplicationInsights => "application-insights",
+    AppMesh => "appmesh",
+    AppStream => "appstream",
+    AppSync => "appsync",
+    Athena => "athena",
+    AuditManager => "auditmanager",
+    AutoScaling => "autoscaling",
+    AutoScalingPlans => "autoscaling-plans",
+    Backup => "backup",
+    Batch => "batch",
+    Braket => "braket",
+    Budgets => "budgets",
+    CostExplorer => "ce",
+    Chime => "chime",
+    Cloud9 => "cloud9",
+    CloudDirectory => "clouddirectory",
+    CloudFormation => "cloudformation",
+    CloudHsm => "cloudhsm",
+    CloudHsmV2 => "cloudhsmv2",
+    CloudSearch => "cloudsearch",
+    CloudSearchDomain => "cloudsearchdomain",
+    CloudTrail => "cloudtrail",
+    CloudWatch => "cloudwatch",
+    CodeArtifact => "codeartifact",
+    CodeBuild => "codebuild",
+    CodeCommit => "codecommit",
+    CodeDeploy => "codedeploy",
+    CodeGuruReviewer => "codeguru-reviewer",
+    CodeGuruProfiler => "codeguruprofiler",
+    CodePipeline => "codepipeline",
+    CodeStar => "codestar",
+    CodeStarConnections => "codestar-connections",
+    CodeStarNotifications => "codestar-notifications",
+    CognitoIdentity => "cognito-identity",
+    CognitoIdentityProvider => "cognito-idp",
+    CognitoSync => "cognito-sync",
+    Comprehend => "comprehend",
+    ComprehendMedical => "comprehendmedical",
+    ComputeOptimizer => "compute-optimizer",
+    Config => "config",
+    Connect => "connect",
+    ConnectContactLens => "connect-contact-lens",
+    ConnectParticipant => "connectparticipant",
+    CostUsageReport => "cur",
+    CustomerProfiles => "customer-profiles",
+    GlueDataBrew => "databrew",
+    DataExchange => "dataexchange",
+    DataPipeline => "datapipeline",
+    DataSync => "datasync",
+    DynamoDbAccelerator => "dax",
+    Detective => "detective",
+    DeviceFarm => "devicefarm",
+    DevOpsGuru => "devops-guru",
+    DirectConnect => "directconnect",
+    Discovery => "discovery",
+    DataLifecycleManager => "dlm",
+    DatabaseMigration => "dms",
+    DocumentDb => "docdb",
+    DynamoDb => "dynamodb",
+    DynamoDbStreams => "dynamodbstreams",
+    ElasticBlockStore => "ebs",
+    Ec2 => "ec2",
+    Ec2InstanceConnect => "ec2-instance-connect",
+    Ec2ContainerRegistry => "ecr",
+    Ec2containerRegistryPublic => "ecr-public",
+    Ec2ContainerService => "ecs",
+    ElasticFileSystem => "efs",
+    ElasticKubernetes => "eks",
+    ElasticInference => "elastic-inference",
+    Elasticache => "elasticache",
+    ElasticBeanstalk => "elasticbeanstalk",
+    ElasticTranscoder => "elastictranscoder",
+    ElasticLoadBalancing => "elb",
+    ElasticLoadBalancingV2 => "elbv2",
+    ElasticMapReduce => "emr",
+    ElasticMapReduceContainers => "emr-containers",
+    ElasticsearchService => "es",
+    EventBridge => "events",
+    Firehose => "firehose",
+    FaultInjectionSimulator => "fis",
+    FirewallManagementService => "fms",
+    ForecastService => "forecast",
+    ForecastQueryService => "forecastquery",
+    FraudDetector => "frauddetector",
+    Fsx => "fsx",
+    GameLift => "gamelift",
+    Glacier => "glacier",
+    GlobalAccelerator => "globalaccelerator",
+    Glue => "glue",
+    Greengrass => "greengrass",
+    GreengrassV2 => "greengrassv2",
+    GroundStation => "groundstation",
+    GuardDuty => "guardduty",
+    Health => "health",
+    HealthLake => "healthlake",
+    Honeycode => "honeycode",
+    IdentityAccessManagement => "iam",
+    IdentityStore => "identitystore",
+    ImageBuilder => "imagebuilder",
+    ImportExport => "importexport",
+    Inspector => "inspector",
+    IoT => "iot",
+    IoTData => "iot-data",
+    IoTJobsData => "iot-jobs-data",
+    IoT1clickDevices => "iot1click-devices",
+    IoT1clickProjects => "iot1click-projects",
+    IoTAnalytics => "iotanalytics",
+    IoTDeviceAdvisor => "iotdeviceadvisor",
+    IoTEvents => "iotevents",
+    IoTEventsData => "iotevents-data",
+    IoTFleetHub => "iotfleethub",
+    IoTSecureTunneling => "iotsecuretunneling",
+    IoTSitewise => "iotsitewise",
+    IoTThingsGraph => "iotthingsgraph",
+    IoTWireless => "iotwireless",
+    InteractiveVideo => "ivs",
+    Kafka => "kafka",
+    Kendra => "kendra",
+    Kinesis => "kinesis",
+    KinesisVideoArchivedMedia => "kinesis-video-archived-media",
+    KinesisVideoMedia => "kinesis-video-media",
+    KinesisVideoSignaling => "kinesis-video-signaling",
+    KinesisAnalytics => "kinesisanalytics",
+    KinesisAnalyticsV2 => "kinesisanalyticsv2",
+    KinesisVideo => "kinesisvideo",
+    KeyManagement => "kms",
+    LakeFormation => "lakeformation",
+    Lambda => "lambda",
+    LexModels => "lex-models",
+    LexRuntime => "lex-runtime",
+    LexV2Models => "lexv2-models",
+    LexV2Runtime => "lexv2-runtime",
+    LicenseManager => "license-manager",
+    Lightsail => "lightsail",
+    Location => "location",
+    CloudWatchLogs => "logs",
+    LookoutEquipment => "lookoutequipment",
+    LookoutMetrics => "lookoutmetrics",
+    LookoutVision => "lookoutvision",
+    MachineLearning => "machinelearning",
+    Macie => "macie",
+    Macie2 => "macie2",
+    ManagedBlockchain => "managedblockchain",
+    MarketplaceCatalog => "marketplace-catalog",
+    MarketplaceEntitlement => "marketplace-entitlement",
+    MarketplaceCommerceAnalytics => "marketplacecommerceanalytics",
+    MediaConnect => "mediaconnect",
+    MediaConvert => "mediaconvert",
+    MediaLive => "medialive",
+    MediaPackage => "mediapackage",
+    MediaPackageVod => "mediapackage-vod",
+    MediaStore => "mediastore",
+    MediaStoreData => "mediastore-data",
+    MediaTailor => "mediatailor",
+    MarketplaceMetering => "meteringmarketplace",
+    MigrationHub => "mgh",
+    ApplicationMigration => "mgn",
+    MigrationHubConfig => "migrationhub-config",
+    Mobile => "mobile",
+    Mq => "mq",
+    MechanicalTurk => "mturk",
+    ManagedWorkflowsForApacheAirflow => "mwaa",
+    Neptune => "neptune",
+    NetworkFirewall => "network-firewall",
+    NetworkManager => "networkmanager",
+    OpsWorks => "opsworks",
+    OpsWorksCm => "opsworkscm",
+    Organizations => "organizations",
+    Outposts => "outposts",
+    Personalize => "personalize",
+    PersonalizeEvents => "personalize-events",
+    PersonalizeRuntime => "personalize-runtime",
+    PerformanceInsights => "pi",
+    Pinpoint => "pinpoint",
+    PinpointEmail => "pinpoint-email",
+    PinpointSmsVoice => "pinpoint-sms-voice",
+    EventBridgePipes => "pipes",
+    Polly => "polly",
+    Pricing => "pricing",
+    Qldb => "qldb",
+    QldbSession => "qldb-session",
+    QuickSight => "quicksight",
+    ResourceAccessManager => "ram",
+    RelationalDatabaseService => "rds",
+    RdsDataService => "rds-data",
+    Redshift => "redshift",
+    RedshiftDataApiService => "redshift-data",
+    Rekognition => "rekognition",
+    ResourceGroups => "resource-groups",
+    ResourceGroupsTaggingApi => "resourcegroupstaggingapi",
+    RoboMaker => "robomaker",
+    Route53 => "route53",
+    Route53Domains => "route53domains",
+    Route53Resolver => "route53resolver",
+    S3 => "s3",
+    S3Control => "s3control",
+    S3ObjectLambda => "s3-object-lambda",
+    S3Outposts => "s3-outposts",
+    S3Express => "s3express",
+    SageMaker => "sagemaker",
+    AugmentedAiRuntime => "sagemaker-a2i-runtime",
+    SagemakerEdgeManager => "sagemaker-edge",
+    SageMakerFeatureStoreRuntime => "sagemaker-featurestore-runtime",
+    SageMakerRuntime => "sagemaker-runtime",
+    SavingsPlans => "savingsplans",
+    EventBridgeScheduler => "scheduler",
+    EventBridgeSchemaRegistry => "schemas",
+    SimpleDb => "sdb",
+    SecretsManager => "secretsmanager",
+    SecurityHub => "securityhub",
+    ServerlessApplicationRepository => "serverlessrepo",
+    ServiceQuotas => "service-quotas",
+    ServiceCatalog => "servicecatalog",
+    ServiceCatalogAppRegistry => "servicecatalog-appregistry",
+    ServiceDiscovery => "servicediscovery",
+    SimpleEmail => "ses",
+    SimpleEmailV2 => "sesv2",
+    Shield => "shield",
+    Signer => "signer",
+    ServerMigration => "sms",
+    Snowball => "snowball",
+    SimpleNotification => "sns",
+    SimpleQueue => "sqs",
+    SimpleSystemsManager => "ssm",
+    SingleSignOn => "sso",
+    SingleSignOnAdmin => "sso-admin",
+    SingleSignOnOpenIdConnect => "sso-oidc",
+    StepFunctions => "stepfunctions",
+    StorageGateway => "storagegateway",
+    SecurityToken => "sts",
+    Support => "support",
+    SimpleWorkflow => "swf",
+    CloudWatchSynthetics => "synthetics",
+    Textract => "textract",
+    TimestreamQuery => "timestream-query",
+    TimestreamWrite => "timestream-write",
+    Transcribe => "transcribe",
+    Transfer => "transfer",
+    Translate => "translate",
+    WebApplicationFirewall => "waf",
+    WebApplicationFirewallRegional => "waf-regional",
+    WebApplicationFirewallV2 => "wafv2",
+    WellArchitected => "wellarchitected",
+    WorkDocs => "workdocs",
+    WorkLink => "worklink",
+    WorkMail => "workmail",
+    WorkMailMessageFlow => "workmailmessageflow",
+    WorkSpaces => "workspaces",
+    XRay => "xray",
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Partition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Identifier::from(self.clone()))
+    }
+}
+
+impl FromStr for Partition {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "aws" => Ok(Partition::Aws),
+            "aws-cn" => Ok(Partition::AwsChina),
+            "aws-us-gov" => Ok(Partition::AwsUsGov),
+            _ => Err(Error::InvalidPartition),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Identifier::from(self.clone()))
+    }
+}
+
+impl FromStr for Region {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "af-south-1" => Ok(Region::AfSouth1),
+            "ap-east-1" => Ok(Region::ApEast1),
+            "ap-northeast-1" => Ok(Region::ApNortheast1),
+            "ap-northeast-2" => Ok(Region::ApNortheast2),
+            "ap-northeast-3" => Ok(Region::ApNortheast3),
+            "ap-southeast-1" => Ok(Region::ApSoutheast1),
+            "ap-southeast-2" => Ok(Region::ApSoutheast2),
+            "ap-south-1" => Ok(Region::ApSouth1),
+            "ca-central-1" => Ok(Region::CaCentral1),
+            "eu-central-1" => Ok(Region::EuCentral1),
+            "eu-north-1" => Ok(Region::EuNorth1),
+            "eu-south-1" => Ok(Region::EuSouth1),
+            "eu-west-1" => Ok(Region::EuWest1),
+            "eu-west-2" => Ok(Region::EuWest2),
+            "eu-west-3" => Ok(Region::EuWest3),
+            "me-south-1" => Ok(Region::MeSouth1),
+            "sa-east-1" => Ok(Region::SaEast1),
+            "us-east-1" => Ok(Region::UsEast1),
+            "us-east-2" => Ok(Region::UsEast2),
+            "us-west-1" => Ok(Region::UsWest1),
+            "us-west-2" => Ok(Region::UsWest2),
+            _ => Err(Error::InvalidRegion),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Service {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Identifier::from(self.clone()))
+    }
+}
+
+impl Service {
+    /// Returns whether the region component is present in ResourceNames for this service. For
+    /// services not listed explicitly this is `Presence::Optional`.
+    pub fn region_presence(&self) -> Presence {
+        match self {
+            Service::Budgets
+            | Service::Chime
+            | Service::CostExplorer
+            | Service::GlobalAccelerator
+            | Service::IdentityAccessManagement
+            | Service::NetworkManager
+            | Service::Organizations
+            | Service::Route53
+            | Service::SecurityToken
+            | Service::Shield
+            | Service::WebApplicationFirewall => Presence::NotAllowed,
+            Service::CloudWatchLogs
+            | Service::CognitoIdentity
+            | Service::CognitoIdentityProvider
+            | Service::CognitoSync
+            | Service::EventBridge
+            | Service::EventBridgePipes
+            | Service::EventBridgeScheduler
+            | Service::KeyManagement
+            | Service::Lambda
+            | Service::S3ObjectLambda
+            | Service::S3Outposts
+            | Service::S3Express
+            | Service::SecretsManager
+            | Service::SimpleNotification
+            | Service::SimpleQueue => Presence::Required,
+            _ => Presence::Optional,
+        }
+    }
+
+    /// Returns whether the region component is present in the ResourceName for `resource` in this
+    /// service. This is the same as `region_presence` except for services where it depends on the
+    /// resource type, such as S3 buckets and DynamoDB global tables which have no region.
+    pub fn region_presence_for(&self, resource: &str) -> Presence {
+        match self {
+            Service::S3 if is_s3_regional_resource(resource) => Presence::Optional,
+            Service::S3 => Presence::NotAllowed,
+            Service::DynamoDb if resource.starts_with(DYNAMODB_GLOBAL_TABLE_PREFIX) => {
+                Presence::NotAllowed
             }
-            Service::ServiceDiscovery => Identifier::new_unchecked("servicediscovery"),
-            Service::SimpleEmail => Identifier::new_unchecked("ses"),
-            Service::SimpleEmailV2 => Identifier::new_unchecked("sesv2"),
-            Service::Shield => Identifier::new_unchecked("shield"),
-            Service::Signer => Identifier::new_unchecked("signer"),
-            Service::ServerMigration => Identifier::new_unchecked("sms"),
-            Service::Snowball => Identifier::new_unchecked("snowball"),
-            Service::SimpleNotification => Identifier::new_unchecked("sns"),
-            Service::SimpleQueue => Identifier::new_unchecked("sqs"),
-            Service::SimpleSystemsManager => Identifier::new_unchecked("ssm"),
-            Service::SingleSignOn => Identifier::new_unchecked("sso"),
-            Service::SingleSignOnAdmin => Identifier::new_unchecked("sso-admin"),
-            Service::SingleSignOnOpenIdConnect => Identifier::new_unchecked("sso-oidc"),
-            Service::StepFunctions => Identifier::new_unchecked("stepfunctions"),
-            Service::StorageGateway => Identifier::new_unchecked("storagegateway"),
-            Service::SecurityToken => Identifier::new_unchecked("sts"),
-            Service::Support => Identifier::new_unchecked("support"),
-            Service::SimpleWorkflow => Identifier::new_unchecked("swf"),
-            Service::CloudWatchSynthetics => Identifier::new_unchecked("synthetics"),
-            Service::Textract => Identifier::new_unchecked("textract"),
-            Service::TimestreamQuery => Identifier::new_unchecked("timestream-query"),
-            Service::TimestreamWrite => Identifier::new_unchecked("timestream-write"),
-            Service::Transcribe => Identifier::new_unchecked("transcribe"),
-            Service::Transfer => Identifier::new_unchecked("transfer"),
-            Service::Translate => Identifier::new_unchecked("translate"),
-            Service::WebApplicationFirewall => Identifier::new_unchecked("waf"),
-            Service::WebApplicationFirewallRegional => Identifier::new_unchecked("waf-regional"),
-            Service::WebApplicationFirewallV2 => Identifier::new_unchecked("wafv2"),
-            Service::WellArchitected => Identifier::new_unchecked("wellarchitected"),
-            Service::WorkDocs => Identifier::new_unchecked("workdocs"),
-            Service::WorkLink => Identifier::new_unchecked("worklink"),
-            Service::WorkMail => Identifier::new_unchecked("workmail"),
-            Service::WorkMailMessageFlow => Identifier::new_unchecked("workmailmessageflow"),
-            Service::WorkSpaces => Identifier::new_unchecked("workspaces"),
-            Service::XRay => Identifier::new_unchecked("xray"),
+            Service::DynamoDb => Presence::Required,
+            _ => self.region_presence(),
+        }
+    }
+
+    /// Returns whether the account component is present in ResourceNames for this service. For
+    /// services not listed explicitly this is `Presence::Optional`.
+    pub fn account_presence(&self) -> Presence {
+        match self {
+            Service::Route53 => Presence::NotAllowed,
+            Service::CloudWatchLogs
+            | Service::CognitoIdentity
+            | Service::CognitoIdentityProvider
+            | Service::CognitoSync
+            | Service::DynamoDb
+            | Service::EventBridge
+            | Service::EventBridgePipes
+            | Service::EventBridgeScheduler
+            | Service::IdentityAccessManagement
+            | Service::KeyManagement
+            | Service::Lambda
+            | Service::S3ObjectLambda
+            | Service::S3Outposts
+            | Service::S3Express
+            | Service::SecretsManager
+            | Service::SecurityToken
+            | Service::SimpleNotification
+            | Service::SimpleQueue => Presence::Required,
+            _ => Presence::Optional,
         }
     }
+
+    /// Returns whether the account component is present in the ResourceName for `resource` in
+    /// this service. This is the same as `account_presence` except for services where it depends
+    /// on the resource type, such as S3 buckets which have no account.
+    pub fn account_presence_for(&self, resource: &str) -> Presence {
+        match self {
+            Service::S3 if is_s3_regional_resource(resource) => Presence::Required,
+            Service::S3 => Presence::NotAllowed,
+            _ => self.account_presence(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ManagedPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for ManagedPolicy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ManagedPolicy::ALL
+            .iter()
+            .find(|policy| policy.name() == s)
+            .copied()
+            .ok_or_else(|| Error::InvalidResource(s.to_string()))
+    }
+}
+
+impl ManagedPolicy {
+    /// Returns the ResourceName of this policy in the provided partition, AWS managed policies are
+    /// owned by the account `aws`.
+    pub fn to_arn(&self, partition: Partition) -> ResourceName {
+        let resource = if self.path().is_empty() {
+            format!("policy/{}", self.name())
+        } else {
+            format!("policy/{}/{}", self.path(), self.name())
+        };
+        ResourceName {
+            partition: Some(partition.into()),
+            service: Service::IdentityAccessManagement.into(),
+            region: None,
+            account_id: Some(AccountIdentifier::new_unchecked("aws")),
+            resource: ResourceIdentifier::new_unchecked(&resource),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ServiceLinkedRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for ServiceLinkedRole {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ServiceLinkedRole::ALL
+            .iter()
+            .find(|role| role.name() == s)
+            .copied()
+            .ok_or_else(|| Error::InvalidResource(s.to_string()))
+    }
+}
+
+impl ServiceLinkedRole {
+    /// Returns the ResourceName of this role, as created in the provided account and partition.
+    pub fn to_arn(&self, partition: Partition, account: AccountIdentifier) -> ResourceName {
+        ResourceName {
+            partition: Some(partition.into()),
+            service: Service::IdentityAccessManagement.into(),
+            region: None,
+            account_id: Some(account),
+            resource: ResourceIdentifier::new_unchecked(&format!(
+                "role/aws-service-role/{}/{}",
+                self.service_principal(),
+                self.name()
+            )),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const DYNAMODB_GLOBAL_TABLE_PREFIX: &str = "global-table/";
+
+const S3_REGIONAL_RESOURCE_TYPES: &[&str] = &[
+    "accesspoint",
+    "async-request",
+    "job",
+    "storage-lens",
+    "storage-lens-group",
+];
+
+fn is_s3_regional_resource(resource: &str) -> bool {
+    let resource_type = resource.split('/').next().unwrap_or_default();
+    S3_REGIONAL_RESOURCE_TYPES.contains(&resource_type)
 }
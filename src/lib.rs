@@ -78,16 +78,19 @@
 * at this time there are few supported services.
 *
 * ```rust
-* use aws_arn::builder::s3;
-* use aws_arn::Identifier;
+* use aws_arn::builder::s3::{self, BucketName, ObjectKey};
 * use std::str::FromStr;
 *
 * let arn = s3::object(
-*     Identifier::from_str("mythings").unwrap(),
-*     Identifier::from_str("thing-1").unwrap(),
-* );
+*     BucketName::from_str("mythings").unwrap(),
+*     ObjectKey::from_str("thing-1").unwrap(),
+* )
+* .expect("not a valid bucket or object name");
 * ```
 *
+* ResourceName literals can also be checked at compile time with the `arn!` macro from the
+* companion `aws-arn-macros` crate.
+*
 * For more, see the AWS documentation for [Amazon Resource Name
 * (ResourceName)](https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html) documentation.
 *
@@ -194,7 +197,8 @@ pub struct Identifier(String);
 
 ///
 /// A string value that is used to capture the account ID component
-/// of an ResourceName. These are ASCII digits only and a fixed length of 12 characters.
+/// of an ResourceName. These are ASCII digits only and a fixed length of 12 characters, with the
+/// exception of the value `aws` used for resources owned by AWS itself, such as managed policies.
 ///
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde_support", derive(Deserialize, Serialize))]
@@ -288,6 +292,8 @@ const REQUIRED_COMPONENT_COUNT: usize = 6;
 const PARTITION_AWS_PREFIX: &str = "aws";
 const PARTITION_AWS_OTHER_PREFIX: &str = "aws-";
 
+const ACCOUNT_AWS_OWNED: &str = "aws";
+
 lazy_static! {
     static ref REGEX_VARIABLE: Regex = Regex::new(r"\$\{([^$}]+)\}").unwrap();
 }
@@ -389,7 +395,8 @@ impl IdentifierLike for AccountIdentifier {
     }
 
     fn is_valid(s: &str) -> bool {
-        (s.len() == 12 && s.chars().all(|c| c.is_ascii_digit()))
+        s == ACCOUNT_AWS_OWNED
+            || (s.len() == 12 && s.chars().all(|c| c.is_ascii_digit()))
             || (!s.is_empty()
                 && s.len() <= 12
                 && s.chars()
@@ -554,7 +561,7 @@ impl Display for ResourceName {
         write!(
             f,
             "{}",
-            vec![
+            [
                 ARN_PREFIX.to_string(),
                 self.partition
                     .as_ref()
@@ -664,6 +671,80 @@ impl ResourceName {
             ..self.clone()
         })
     }
+
+    /// Return a copy of this ResourceName in the provided `partition`, this will fail if the
+    /// partition is not `aws` or does not have the `aws-` prefix.
+    pub fn with_partition(&self, partition: Identifier) -> Result<Self, Error> {
+        if partition.deref() == PARTITION_AWS_PREFIX
+            || partition.starts_with(PARTITION_AWS_OTHER_PREFIX)
+        {
+            Self {
+                partition: Some(partition),
+                ..self.clone()
+            }
+            .validated()
+        } else {
+            Err(Error::InvalidPartition)
+        }
+    }
+
+    /// Return a copy of this ResourceName in the provided `region`, this will fail if the
+    /// service does not allow a region for this resource.
+    pub fn with_region(&self, region: Identifier) -> Result<Self, Error> {
+        Self {
+            region: Some(region),
+            ..self.clone()
+        }
+        .validated()
+    }
+
+    /// Return a copy of this ResourceName owned by the provided `account`, this will fail if
+    /// the service does not allow an account for this resource.
+    pub fn with_account(&self, account: AccountIdentifier) -> Result<Self, Error> {
+        Self {
+            account_id: Some(account),
+            ..self.clone()
+        }
+        .validated()
+    }
+
+    /// Return a copy of this ResourceName for the provided `resource`, this will fail if the
+    /// region and account of this ResourceName are not valid for the new resource.
+    pub fn with_resource(&self, resource: ResourceIdentifier) -> Result<Self, Error> {
+        Self {
+            resource,
+            ..self.clone()
+        }
+        .validated()
+    }
+
+    #[cfg(not(feature = "known"))]
+    fn validated(self) -> Result<Self, Error> {
+        Ok(self)
+    }
+
+    #[cfg(feature = "known")]
+    fn validated(self) -> Result<Self, Error> {
+        if let Ok(service) = known::Service::from_str(&self.service) {
+            let region = service.region_presence_for(&self.resource);
+            let account = service.account_presence_for(&self.resource);
+            let has_region = self.region.as_ref().is_some_and(|r| !r.is_empty());
+            match region {
+                known::Presence::Required if !has_region => return Err(Error::MissingRegion),
+                known::Presence::NotAllowed if has_region => return Err(Error::RegionNotAllowed),
+                _ => {}
+            }
+            let has_account = self.account_id.as_ref().is_some_and(|a| !a.is_empty());
+            match account {
+                known::Presence::Required if !has_account => return Err(Error::MissingAccountId),
+                known::Presence::NotAllowed if has_account => {
+                    return Err(Error::AccountIdNotAllowed)
+                }
+                _ => {}
+            }
+        }
+        Ok(self)
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -0,0 +1,29 @@
+//!
+//! Fixtures shared by the service builder tests; each test crate uses only some of them.
+//!
+#![allow(dead_code)]
+
+use aws_arn::{AccountIdentifier, Identifier, ResourceIdentifier, ResourceName};
+use std::convert::TryFrom;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+pub fn account() -> AccountIdentifier {
+    AccountIdentifier::from_str("123456789012").unwrap()
+}
+
+pub fn id(s: &str) -> Identifier {
+    Identifier::from_str(s).unwrap()
+}
+
+pub fn resource_id(s: &str) -> ResourceIdentifier {
+    ResourceIdentifier::from_str(s).unwrap()
+}
+
+pub fn parse<T>(s: &str) -> T
+where
+    T: for<'a> TryFrom<&'a ResourceName>,
+    for<'a> <T as TryFrom<&'a ResourceName>>::Error: Debug,
+{
+    T::try_from(&ResourceName::from_str(s).unwrap()).unwrap()
+}
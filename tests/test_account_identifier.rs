@@ -30,6 +30,13 @@ fn test_account_identifier_is_valid() {
     assert!(id.is_plain());
 }
 
+#[test]
+fn test_account_identifier_is_valid_aws_owned() {
+    assert!(AccountIdentifier::is_valid("aws"));
+    assert!(!AccountIdentifier::is_valid("aws-cn"));
+    assert!(AccountIdentifier::new_unchecked("aws").is_plain());
+}
+
 #[test]
 fn test_account_identifier_is_valid_wildcard() {
     assert!(AccountIdentifier::is_valid("*"));
@@ -1,7 +1,7 @@
-use aws_arn::builder::ArnBuilder;
+use aws_arn::builder::{ArnBuilder, ResourceBuilder, Separator};
 use aws_arn::known::Region::UsEast2;
 use aws_arn::known::Service::{Lambda, S3};
-use aws_arn::{AccountIdentifier, Identifier, ResourceIdentifier, ResourceName};
+use aws_arn::{AccountIdentifier, Identifier, IdentifierLike, ResourceIdentifier, ResourceName};
 use std::str::FromStr;
 
 #[test]
@@ -28,3 +28,59 @@ fn test_lambda_layer() {
         "arn:aws:lambda:us-east-2:123456789012:layer:my-layer:3"
     );
 }
+
+#[test]
+fn test_lambda_function_alias() {
+    let arn: ResourceName = ArnBuilder::service_id(Lambda.into())
+        .resource(
+            ResourceBuilder::typed(Identifier::new_unchecked("function"))
+                .qualifier_segment(ResourceIdentifier::new_unchecked("my-function"))
+                .qualifier_segment(ResourceIdentifier::new_unchecked("PROD"))
+                .build(),
+        )
+        .in_region_id(UsEast2.into())
+        .owned_by(AccountIdentifier::from_str("123456789012").unwrap())
+        .into();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:lambda:us-east-2:123456789012:function:my-function:PROD"
+    );
+}
+
+#[test]
+fn test_mixed_separators() {
+    let resource = ResourceBuilder::typed(Identifier::new_unchecked("log-group"))
+        .qualifier_segment(ResourceIdentifier::new_unchecked("/aws/x"))
+        .qualifier_segment(ResourceIdentifier::new_unchecked("log-stream"))
+        .qualifier_segment(ResourceIdentifier::new_unchecked("y"))
+        .build();
+    assert_eq!(resource.to_string(), "log-group:/aws/x:log-stream:y");
+
+    let resource = ResourceBuilder::typed(Identifier::new_unchecked("table"))
+        .path_segment(ResourceIdentifier::new_unchecked("Foo"))
+        .segment(Separator::Path, ResourceIdentifier::new_unchecked("stream"))
+        .path_segment(ResourceIdentifier::new_unchecked("2024-01-01T00:00:00.000"))
+        .build();
+    assert_eq!(
+        resource.to_string(),
+        "table/Foo/stream/2024-01-01T00:00:00.000"
+    );
+}
+
+#[test]
+fn test_iam_role_with_path() {
+    let resource = ResourceBuilder::typed(Identifier::new_unchecked("role"))
+        .path(&[
+            Identifier::new_unchecked("service-role"),
+            Identifier::new_unchecked("team"),
+        ])
+        .path_segment(ResourceIdentifier::new_unchecked("my-role"))
+        .build();
+    assert_eq!(resource.to_string(), "role/service-role/team/my-role");
+
+    let resource = ResourceBuilder::typed(Identifier::new_unchecked("role"))
+        .path(&[])
+        .path_segment(ResourceIdentifier::new_unchecked("my-role"))
+        .build();
+    assert_eq!(resource.to_string(), "role/my-role");
+}
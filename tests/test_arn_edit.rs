@@ -0,0 +1,80 @@
+use aws_arn::builder::ArnBuilder;
+use aws_arn::known::Region::UsWest2;
+use aws_arn::{
+    AccountIdentifier, Error, Identifier, IdentifierLike, ResourceIdentifier, ResourceName,
+};
+use std::str::FromStr;
+
+const FUNCTION: &str = "arn:aws:lambda:us-east-2:123456789012:function:my-function";
+
+#[test]
+fn test_builder_from_arn() {
+    let arn = ResourceName::from_str(FUNCTION).unwrap();
+    let moved: ResourceName = ArnBuilder::from(&arn).in_region(UsWest2).into();
+    assert_eq!(
+        moved.to_string(),
+        "arn:aws:lambda:us-west-2:123456789012:function:my-function"
+    );
+}
+
+#[test]
+fn test_with_region() {
+    let arn = ResourceName::from_str(FUNCTION).unwrap();
+    let moved = arn.with_region(UsWest2.into()).unwrap();
+    assert_eq!(
+        moved.to_string(),
+        "arn:aws:lambda:us-west-2:123456789012:function:my-function"
+    );
+    assert_eq!(arn.to_string(), FUNCTION);
+}
+
+#[test]
+fn test_with_account_and_resource() {
+    let arn = ResourceName::from_str(FUNCTION)
+        .unwrap()
+        .with_account(AccountIdentifier::from_str("210987654321").unwrap())
+        .unwrap()
+        .with_resource(ResourceIdentifier::new_unchecked(
+            "function:my-function:PROD",
+        ))
+        .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:lambda:us-east-2:210987654321:function:my-function:PROD"
+    );
+}
+
+#[test]
+fn test_with_partition() {
+    let arn = ResourceName::from_str(FUNCTION)
+        .unwrap()
+        .with_partition(Identifier::new_unchecked("aws-cn"))
+        .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws-cn:lambda:us-east-2:123456789012:function:my-function"
+    );
+    assert_eq!(
+        arn.with_partition(Identifier::new_unchecked("azure")),
+        Err(Error::InvalidPartition)
+    );
+}
+
+#[test]
+fn test_with_shape_errors() {
+    let role = ResourceName::from_str("arn:aws:iam::123456789012:role/S3Access").unwrap();
+    assert_eq!(
+        role.with_region(UsWest2.into()),
+        Err(Error::RegionNotAllowed)
+    );
+
+    let bucket = ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap();
+    assert_eq!(
+        bucket.with_account(AccountIdentifier::from_str("123456789012").unwrap()),
+        Err(Error::AccountIdNotAllowed)
+    );
+    assert_eq!(
+        bucket.with_resource(ResourceIdentifier::new_unchecked("job/23476")),
+        Err(Error::MissingAccountId)
+    );
+}
@@ -0,0 +1,152 @@
+use aws_arn::builder::cognito::{self, CognitoResource};
+use aws_arn::builder::ServiceResource;
+use aws_arn::known::{Partition, Region};
+use aws_arn::{AccountIdentifier, Error, Identifier, ResourceIdentifier, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+#[test]
+fn test_identity_pool_round_trip() {
+    let arn = cognito::identity_pool(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        AccountIdentifier::from_str("123456789012").unwrap(),
+        ResourceIdentifier::from_str("us-east-1:1cf667a2-49a6-454b-9e45-23199abcdef0").unwrap(),
+    )
+    .unwrap();
+    let typed = CognitoResource::try_from(&arn).unwrap();
+    assert_eq!(ResourceName::from(typed), arn);
+}
+
+#[test]
+fn test_parse_identity_pool() {
+    let arn = ResourceName::from_str(
+        "arn:aws:cognito-identity:us-east-1:123456789012:identitypool/us-east-1:1cf667a2-49a6-454b-9e45-23199EXAMPLE",
+    )
+    .unwrap();
+    let typed = CognitoResource::try_from(&arn).unwrap();
+    match &typed {
+        CognitoResource::IdentityPool {
+            identity_pool_id, ..
+        } => assert_eq!(
+            identity_pool_id,
+            &ResourceIdentifier::from_str("us-east-1:1cf667a2-49a6-454b-9e45-23199EXAMPLE")
+                .unwrap()
+        ),
+        other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(
+        ResourceName::from(typed),
+        arn.with_partition(Partition::Aws.into()).unwrap()
+    );
+}
+
+#[test]
+fn test_invalid_identity_pool_id() {
+    let result = cognito::identity_pool(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        AccountIdentifier::from_str("123456789012").unwrap(),
+        ResourceIdentifier::from_str("my-pool").unwrap(),
+    );
+    assert_eq!(result, Err(Error::InvalidIdentifier("my-pool".to_string())));
+}
+
+#[test]
+fn test_user_pool_round_trip() {
+    let arn = cognito::user_pool(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        AccountIdentifier::from_str("123456789012").unwrap(),
+        Identifier::from_str("us-east-1_aBcDeF123").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:cognito-idp:us-east-1:123456789012:userpool/us-east-1_aBcDeF123"
+    );
+    let typed = CognitoResource::try_from(&arn).unwrap();
+    assert_eq!(typed.service().to_string(), "cognito-idp");
+    assert_eq!(typed.resource_type_name(), "userpool");
+    assert_eq!(ResourceName::from(typed), arn);
+}
+
+#[test]
+fn test_dataset_round_trip() {
+    let arn = cognito::dataset(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        AccountIdentifier::from_str("123456789012").unwrap(),
+        ResourceIdentifier::from_str("us-east-1:1cf667a2-49a6-454b-9e45-23199abcdef0").unwrap(),
+        ResourceIdentifier::from_str("us-east-1:a2b3c4d5-49a6-454b-9e45-23199abcdef0").unwrap(),
+        Identifier::from_str("settings").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:cognito-sync:us-east-1:123456789012:identitypool/us-east-1:1cf667a2-49a6-454b-9e45-23199abcdef0/identity/us-east-1:a2b3c4d5-49a6-454b-9e45-23199abcdef0/dataset/settings"
+    );
+    let typed = CognitoResource::try_from(&arn).unwrap();
+    assert!(matches!(typed, CognitoResource::Dataset { .. }));
+    assert_eq!(typed.service().to_string(), "cognito-sync");
+    assert_eq!(ResourceName::from(typed), arn);
+}
+
+#[test]
+fn test_pool_id_region() {
+    assert_eq!(
+        cognito::user_pool(
+            Partition::Aws.into(),
+            Region::UsWest2.into(),
+            AccountIdentifier::from_str("123456789012").unwrap(),
+            Identifier::from_str("us-east-1_aBcDeF123").unwrap(),
+        ),
+        Err(Error::InvalidRegion)
+    );
+    assert_eq!(
+        cognito::user_pool(
+            Partition::Aws.into(),
+            Region::UsEast1.into(),
+            AccountIdentifier::from_str("123456789012").unwrap(),
+            Identifier::from_str("us-east-1-aBcDeF123").unwrap(),
+        ),
+        Err(Error::InvalidIdentifier("us-east-1-aBcDeF123".to_string()))
+    );
+    assert_eq!(
+        cognito::identity_pool(
+            Partition::Aws.into(),
+            Region::UsWest2.into(),
+            AccountIdentifier::from_str("123456789012").unwrap(),
+            ResourceIdentifier::from_str("us-east-1:1cf667a2-49a6-454b-9e45-23199abcdef0").unwrap(),
+        ),
+        Err(Error::InvalidRegion)
+    );
+
+    let arn = ResourceName::from_str(
+        "arn:aws:cognito-idp:us-west-2:123456789012:userpool/us-east-1_aBcDeF123",
+    )
+    .unwrap();
+    assert_eq!(CognitoResource::try_from(&arn), Err(Error::InvalidRegion));
+
+    let arn =
+        ResourceName::from_str("arn:aws:cognito-idp:us-west-2:123456789012:userpool/*").unwrap();
+    assert!(CognitoResource::try_from(&arn).is_ok());
+
+    let arn = ResourceName::from_str(
+        "arn:aws:cognito-identity:us-west-2:123456789012:userpool/us-west-2_aBcDeF123",
+    )
+    .unwrap();
+    assert!(CognitoResource::try_from(&arn).is_err());
+}
+
+#[test]
+fn test_service_mismatch() {
+    let arn = ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:my-queue").unwrap();
+    assert_eq!(
+        CognitoResource::try_from(&arn),
+        Err(Error::ResourceMismatch {
+            expected: "cognito-identity | cognito-idp | cognito-sync".to_string(),
+            found: "sqs".to_string(),
+        })
+    );
+}
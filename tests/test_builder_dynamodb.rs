@@ -0,0 +1,207 @@
+use aws_arn::builder::dynamodb::{self, DynamoDbResource, StreamLabel};
+use aws_arn::builder::{KnownResource, ServiceResource};
+use aws_arn::known::{Partition, Region};
+use aws_arn::{Error, Identifier, ResourceIdentifier, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::{account, parse};
+
+fn table() -> ResourceName {
+    dynamodb::table(
+        Partition::Aws.into(),
+        Region::UsWest2.into(),
+        account(),
+        Identifier::from_str("Books").unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_table_round_trip() {
+    let arn = table();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:dynamodb:us-west-2:123456789012:table/Books"
+    );
+    let typed = DynamoDbResource::try_from(&arn).unwrap();
+    assert_eq!(
+        typed,
+        DynamoDbResource::Table {
+            partition: Partition::Aws.into(),
+            region: Region::UsWest2.into(),
+            account: account(),
+            table_name: Identifier::from_str("Books").unwrap(),
+        }
+    );
+    assert_eq!(ResourceName::from(typed), arn);
+}
+
+#[test]
+fn test_parse_resource_types() {
+    let arns = [
+        (
+            "arn:aws:dynamodb:us-west-2:123456789012:table/Books/index/Author-index",
+            "index",
+        ),
+        (
+            "arn:aws:dynamodb:us-west-2:123456789012:table/Books/stream/2015-05-11T21:21:33.291",
+            "stream",
+        ),
+        (
+            "arn:aws:dynamodb:us-west-2:123456789012:table/Books/backup/01489602797149-73d8d5bc",
+            "backup",
+        ),
+        (
+            "arn:aws:dynamodb:us-west-2:123456789012:table/Books/export/01234567890123-a1b2c3d4",
+            "export",
+        ),
+        (
+            "arn:aws:dynamodb::123456789012:global-table/Books",
+            "global-table",
+        ),
+    ];
+    for (s, resource_type) in arns.iter() {
+        let arn = ResourceName::from_str(s).unwrap();
+        let typed = DynamoDbResource::try_from(&arn).unwrap();
+        assert_eq!(typed.resource_type_name(), *resource_type);
+        assert_eq!(ResourceName::from(typed).to_string(), *s);
+    }
+}
+
+#[test]
+fn test_stream_label() {
+    let label = StreamLabel::from_str("2015-05-11T21:21:33.291").unwrap();
+    assert_eq!(
+        label,
+        StreamLabel {
+            year: 2015,
+            month: 5,
+            day: 11,
+            hour: 21,
+            minute: 21,
+            second: 33,
+            millisecond: 291,
+        }
+    );
+    assert_eq!(label.to_string(), "2015-05-11T21:21:33.291");
+    for s in [
+        "2015-05-11T21:21:33",
+        "2015-13-11T21:21:33.291",
+        "2015-05-11 21:21:33.291",
+        "2015-05-11T25:21:33.291",
+        "2015-05-1xT21:21:33.291",
+        "2015-02-31T21:21:33.291",
+        "2015-02-29T21:21:33.291",
+        "2015-04-31T21:21:33.291",
+    ]
+    .iter()
+    {
+        assert_eq!(
+            StreamLabel::from_str(s),
+            Err(Error::InvalidIdentifier(s.to_string()))
+        );
+    }
+
+    let arn = ResourceName::from_str(
+        "arn:aws:dynamodb:us-west-2:123456789012:table/Books/stream/2015-05-11T21:21:33.291",
+    )
+    .unwrap();
+    assert_eq!(
+        DynamoDbResource::try_from(&arn).unwrap().stream_label(),
+        Some(label)
+    );
+    assert!(StreamLabel::from_str("2016-02-29T21:21:33.291").is_ok());
+    let arn = ResourceName::from_str(
+        "arn:aws:dynamodb:us-west-2:123456789012:table/Books/stream/yesterday",
+    )
+    .unwrap();
+    assert!(DynamoDbResource::try_from(&arn).is_err());
+}
+
+#[test]
+fn test_stream_pattern() {
+    let s = "arn:aws:dynamodb:us-west-2:123456789012:table/Books/stream/*";
+    let typed: DynamoDbResource = parse(s);
+    assert_eq!(typed.resource_type_name(), "stream");
+    assert_eq!(typed.stream_label(), None);
+    assert_eq!(typed.to_arn().to_string(), s);
+    assert!(matches!(
+        KnownResource::try_from(&ResourceName::from_str(s).unwrap()),
+        Ok(KnownResource::DynamoDb(DynamoDbResource::Stream { .. }))
+    ));
+}
+
+#[test]
+fn test_navigation() {
+    let label = StreamLabel::from_str("2015-05-11T21:21:33.291").unwrap();
+    let stream = dynamodb::stream_from(&table(), label).unwrap();
+    assert_eq!(
+        stream.to_string(),
+        "arn:aws:dynamodb:us-west-2:123456789012:table/Books/stream/2015-05-11T21:21:33.291"
+    );
+    assert_eq!(dynamodb::table_from(&stream), Ok(table()));
+
+    let index =
+        dynamodb::index_from(&table(), Identifier::from_str("Author-index").unwrap()).unwrap();
+    assert_eq!(dynamodb::table_from(&index), Ok(table()));
+    assert_eq!(
+        dynamodb::index_from(&index, Identifier::from_str("Title-index").unwrap()),
+        Err(Error::ResourceMismatch {
+            expected: "table".to_string(),
+            found: "index".to_string(),
+        })
+    );
+
+    let global = dynamodb::global_table(
+        Partition::Aws.into(),
+        account(),
+        Identifier::from_str("Books").unwrap(),
+    )
+    .unwrap();
+    assert!(dynamodb::table_from(&global).is_err());
+}
+
+#[test]
+fn test_global_table_edit() {
+    let global = dynamodb::global_table(
+        Partition::Aws.into(),
+        account(),
+        Identifier::from_str("Books").unwrap(),
+    )
+    .unwrap();
+    assert!(global
+        .with_resource(ResourceIdentifier::from_str("global-table/Authors").unwrap())
+        .is_ok());
+    assert_eq!(
+        global.with_resource(ResourceIdentifier::from_str("table/Authors").unwrap()),
+        Err(Error::MissingRegion)
+    );
+}
+
+#[test]
+fn test_invalid_names() {
+    assert_eq!(
+        dynamodb::table(
+            Partition::Aws.into(),
+            Region::UsWest2.into(),
+            account(),
+            Identifier::from_str("ab").unwrap(),
+        ),
+        Err(Error::InvalidIdentifier("ab".to_string()))
+    );
+    assert!(dynamodb::table(
+        Partition::Aws.into(),
+        Region::UsWest2.into(),
+        account(),
+        Identifier::from_str("Books*").unwrap(),
+    )
+    .is_ok());
+    let arn = ResourceName::from_str("arn:aws:dynamodb:us-west-2:123456789012:global-table/Books")
+        .unwrap();
+    assert_eq!(
+        DynamoDbResource::try_from(&arn),
+        Err(Error::RegionNotAllowed)
+    );
+}
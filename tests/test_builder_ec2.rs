@@ -0,0 +1,140 @@
+use aws_arn::builder::ec2::{self, Ec2Resource};
+use aws_arn::builder::ServiceResource;
+use aws_arn::known::{Partition, Region};
+use aws_arn::{Error, Identifier, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::account;
+
+#[test]
+fn test_instance_round_trip() {
+    let arn = ec2::instance(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        Identifier::from_str("i-0123456789abcdef0").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ec2:us-east-1:123456789012:instance/i-0123456789abcdef0"
+    );
+    let typed = Ec2Resource::try_from(&arn).unwrap();
+    assert_eq!(
+        typed,
+        Ec2Resource::Instance {
+            partition: Partition::Aws.into(),
+            region: Region::UsEast1.into(),
+            account: account(),
+            instance_id: Identifier::from_str("i-0123456789abcdef0").unwrap(),
+        }
+    );
+    assert_eq!(ResourceName::from(typed), arn);
+}
+
+#[test]
+fn test_parse_resource_types() {
+    let arns = [
+        (
+            "arn:aws:ec2:us-east-1:123456789012:volume/vol-1234abcd",
+            "volume",
+        ),
+        (
+            "arn:aws:ec2:us-east-1:123456789012:snapshot/snap-1234567890abcdef0",
+            "snapshot",
+        ),
+        ("arn:aws:ec2:us-east-1::snapshot/snap-1234abcd", "snapshot"),
+        ("arn:aws:ec2:us-east-1:123456789012:vpc/vpc-1a2b3c4d", "vpc"),
+        (
+            "arn:aws:ec2:us-east-1:123456789012:subnet/subnet-0123456789abcdef0",
+            "subnet",
+        ),
+        (
+            "arn:aws:ec2:us-east-1:123456789012:security-group/sg-1a2b3c4d",
+            "security-group",
+        ),
+        (
+            "arn:aws:ec2:us-east-1:123456789012:network-interface/eni-0123456789abcdef0",
+            "network-interface",
+        ),
+        (
+            "arn:aws:ec2:us-east-1:123456789012:launch-template/lt-0123456789abcdef0",
+            "launch-template",
+        ),
+        (
+            "arn:aws:ec2:us-east-1::image/ami-0123456789abcdef0",
+            "image",
+        ),
+        (
+            "arn:aws:ec2:us-east-1:123456789012:image/ami-1a2b3c4d",
+            "image",
+        ),
+    ];
+    for (s, resource_type) in arns.iter() {
+        let arn = ResourceName::from_str(s).unwrap();
+        let typed = Ec2Resource::try_from(&arn).unwrap();
+        assert_eq!(typed.resource_type_name(), *resource_type);
+        assert_eq!(ResourceName::from(typed).to_string(), *s);
+    }
+}
+
+#[test]
+fn test_public_image() {
+    let arn = ec2::image(
+        Partition::Aws.into(),
+        Region::UsWest2.into(),
+        None,
+        Identifier::from_str("ami-0123456789abcdef0").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ec2:us-west-2::image/ami-0123456789abcdef0"
+    );
+    assert!(matches!(
+        Ec2Resource::try_from(&arn).unwrap(),
+        Ec2Resource::Image { account: None, .. }
+    ));
+}
+
+#[test]
+fn test_invalid_ids() {
+    for id in [
+        "vol-1234abcd",
+        "i-1234abc",
+        "i-0123456789ABCDEF0",
+        "i-1234abcg",
+    ]
+    .iter()
+    {
+        assert_eq!(
+            ec2::instance(
+                Partition::Aws.into(),
+                Region::UsEast1.into(),
+                account(),
+                Identifier::from_str(id).unwrap(),
+            ),
+            Err(Error::InvalidIdentifier(id.to_string()))
+        );
+    }
+    assert!(ec2::security_group(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        Identifier::from_str("sg-*").unwrap(),
+    )
+    .is_ok());
+
+    let arn =
+        ResourceName::from_str("arn:aws:ec2:us-east-1:123456789012:vpc/subnet-1a2b3c4d").unwrap();
+    assert_eq!(
+        Ec2Resource::try_from(&arn),
+        Err(Error::InvalidResource("vpc/subnet-1a2b3c4d".to_string()))
+    );
+    let arn = ResourceName::from_str("arn:aws:ec2:us-east-1::vpc/vpc-1a2b3c4d").unwrap();
+    assert_eq!(Ec2Resource::try_from(&arn), Err(Error::MissingAccountId));
+    let arn = ResourceName::from_str("arn:aws:ec2:us-east-1:123456789012:key-pair/my-key").unwrap();
+    assert!(Ec2Resource::try_from(&arn).is_err());
+}
@@ -0,0 +1,259 @@
+use aws_arn::builder::events::{self, EventsResource};
+use aws_arn::builder::{KnownResource, ServiceResource};
+use aws_arn::known::{Partition, Region};
+use aws_arn::{Error, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::{account, id, parse, resource_id as bus};
+
+fn rule(event_bus_name: Option<&str>, name: &str) -> ResourceName {
+    events::rule(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        event_bus_name.map(bus),
+        id(name),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_event_bus_round_trip() {
+    let arn = events::event_bus(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        bus("aws.partner/example.com/123/orders"),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:events:us-east-1:123456789012:event-bus/aws.partner/example.com/123/orders"
+    );
+    let typed = EventsResource::try_from(&arn).unwrap();
+    assert_eq!(typed.resource_type_name(), "event-bus");
+    assert_eq!(typed.to_arn(), arn);
+}
+
+#[test]
+fn test_rules_on_default_and_custom_buses() {
+    let default_rule = rule(None, "my-rule");
+    assert_eq!(
+        default_rule.to_string(),
+        "arn:aws:events:us-east-1:123456789012:rule/my-rule"
+    );
+    assert_eq!(rule(Some("default"), "my-rule"), default_rule);
+
+    let custom_rule = rule(Some("orders"), "my-rule");
+    assert_eq!(
+        custom_rule.to_string(),
+        "arn:aws:events:us-east-1:123456789012:rule/orders/my-rule"
+    );
+    assert_eq!(
+        EventsResource::try_from(&custom_rule).unwrap(),
+        EventsResource::Rule {
+            partition: Partition::Aws.into(),
+            region: Region::UsEast1.into(),
+            account: account(),
+            event_bus_name: Some(bus("orders")),
+            name: id("my-rule"),
+        }
+    );
+    assert_eq!(
+        parse::<EventsResource>("arn:aws:events:us-east-1:123456789012:rule/default/my-rule")
+            .to_arn(),
+        default_rule
+    );
+    let partner: EventsResource = parse(
+        "arn:aws:events:us-east-1:123456789012:rule/aws.partner/example.com/123/orders/my-rule",
+    );
+    assert!(matches!(
+        &partner,
+        EventsResource::Rule { event_bus_name: Some(b), name, .. }
+            if b.to_string() == "aws.partner/example.com/123/orders" && name.to_string() == "my-rule"
+    ));
+}
+
+#[test]
+fn test_event_bus_from() {
+    assert_eq!(
+        events::event_bus_from(&rule(Some("orders"), "my-rule"))
+            .unwrap()
+            .to_string(),
+        "arn:aws:events:us-east-1:123456789012:event-bus/orders"
+    );
+    assert_eq!(
+        events::event_bus_from(&rule(None, "my-rule"))
+            .unwrap()
+            .to_string(),
+        "arn:aws:events:us-east-1:123456789012:event-bus/default"
+    );
+}
+
+#[test]
+fn test_event_bus_from_partial() {
+    let partial = |s: &str| {
+        events::event_bus_from_partial(Partition::Aws.into(), Region::UsEast1.into(), account(), s)
+    };
+    assert_eq!(
+        partial("orders").unwrap().to_string(),
+        "arn:aws:events:us-east-1:123456789012:event-bus/orders"
+    );
+    let cross_account = "arn:aws:events:us-west-2:210987654321:event-bus/central";
+    assert_eq!(partial(cross_account).unwrap().to_string(), cross_account);
+    assert!(matches!(
+        partial("arn:aws:events:us-east-1:123456789012:rule/my-rule"),
+        Err(Error::ResourceMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_archives_replays_connections_and_destinations() {
+    let archive = events::archive(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        id("my-archive"),
+    )
+    .unwrap();
+    assert_eq!(
+        archive.to_string(),
+        "arn:aws:events:us-east-1:123456789012:archive/my-archive"
+    );
+    assert_eq!(
+        EventsResource::try_from(&archive).unwrap().to_arn(),
+        archive
+    );
+
+    let replay = events::replay(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        id("my-replay"),
+    )
+    .unwrap();
+    assert_eq!(
+        EventsResource::try_from(&replay)
+            .unwrap()
+            .resource_type_name(),
+        "replay"
+    );
+
+    let uuid = "a1b2c3d4-5678-90ab-cdef-0123456789ab";
+    let connection = events::connection(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        id("my-connection"),
+        Some(id(uuid)),
+    )
+    .unwrap();
+    assert_eq!(
+        connection.to_string(),
+        format!(
+            "arn:aws:events:us-east-1:123456789012:connection/my-connection/{}",
+            uuid
+        )
+    );
+    assert_eq!(
+        EventsResource::try_from(&connection).unwrap().to_arn(),
+        connection
+    );
+
+    let destination = events::api_destination(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        id("my-destination"),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        destination.to_string(),
+        "arn:aws:events:us-east-1:123456789012:api-destination/my-destination"
+    );
+    let typed = EventsResource::try_from(&destination).unwrap();
+    assert_eq!(typed.resource_type_name(), "api-destination");
+    assert_eq!(typed.to_arn(), destination);
+
+    assert!(events::connection(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        id("my-connection"),
+        Some(id("a1b2c3d4-5678-90ab-cdef-EXAMPLE11111")),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_pipes_and_schedules() {
+    let pipe = events::pipe(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        id("my-pipe"),
+    )
+    .unwrap();
+    assert_eq!(
+        pipe.to_string(),
+        "arn:aws:pipes:us-east-1:123456789012:pipe/my-pipe"
+    );
+    let typed = EventsResource::try_from(&pipe).unwrap();
+    assert_eq!(typed.service().to_string(), "pipes");
+    assert_eq!(typed.to_arn(), pipe);
+
+    let schedule = events::schedule(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        id("default"),
+        id("nightly"),
+    )
+    .unwrap();
+    assert_eq!(
+        schedule.to_string(),
+        "arn:aws:scheduler:us-east-1:123456789012:schedule/default/nightly"
+    );
+    let typed = EventsResource::try_from(&schedule).unwrap();
+    assert_eq!(typed.service().to_string(), "scheduler");
+    assert_eq!(typed.to_arn(), schedule);
+
+    let group = events::schedule_group(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        id("reports"),
+    )
+    .unwrap();
+    assert_eq!(
+        group.to_string(),
+        "arn:aws:scheduler:us-east-1:123456789012:schedule-group/reports"
+    );
+    assert!(matches!(
+        KnownResource::try_from(&group),
+        Ok(KnownResource::Events(EventsResource::ScheduleGroup { .. }))
+    ));
+}
+
+#[test]
+fn test_parse_errors() {
+    let arn = ResourceName::from_str("arn:aws:pipes:us-east-1:123456789012:rule/my-rule").unwrap();
+    assert_eq!(
+        EventsResource::try_from(&arn),
+        Err(Error::InvalidResource("rule/my-rule".to_string()))
+    );
+    let arn = ResourceName::from_str("arn:aws:scheduler:us-east-1:123456789012:schedule/nightly")
+        .unwrap();
+    assert!(EventsResource::try_from(&arn).is_err());
+    let arn = ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:my-queue").unwrap();
+    assert_eq!(
+        EventsResource::try_from(&arn),
+        Err(Error::ResourceMismatch {
+            expected: "events | pipes | scheduler".to_string(),
+            found: "sqs".to_string(),
+        })
+    );
+}
@@ -0,0 +1,230 @@
+use aws_arn::builder::iam::{self, IamResource};
+use aws_arn::known::Partition;
+use aws_arn::{Error, Identifier, ResourceIdentifier, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::account;
+
+#[test]
+fn test_root_round_trip() {
+    let arn = iam::root(account());
+    let typed = IamResource::try_from(&arn).unwrap();
+    assert!(matches!(typed, IamResource::Root { .. }));
+    assert_eq!(ResourceName::from(typed).to_string(), arn.to_string());
+}
+
+#[test]
+fn test_role_round_trip() {
+    let arn = iam::role(
+        Partition::Aws.into(),
+        account(),
+        Identifier::from_str("S3Access").unwrap(),
+    )
+    .unwrap();
+    let typed = IamResource::try_from(&arn).unwrap();
+    assert_eq!(
+        typed,
+        IamResource::Role {
+            partition: Partition::Aws.into(),
+            account: account(),
+            path: vec![],
+            name: Identifier::from_str("S3Access").unwrap(),
+        }
+    );
+    assert_eq!(ResourceName::from(typed), arn);
+}
+
+#[test]
+fn test_user_with_path() {
+    let arn = ResourceName::from_str(
+        "arn:aws:iam::123456789012:user/division_abc/subdivision_xyz/JaneDoe",
+    )
+    .unwrap();
+    let typed = IamResource::try_from(&arn).unwrap();
+    assert_eq!(
+        typed,
+        IamResource::User {
+            partition: Partition::Aws.into(),
+            account: account(),
+            path: vec![
+                Identifier::from_str("division_abc").unwrap(),
+                Identifier::from_str("subdivision_xyz").unwrap(),
+            ],
+            name: Identifier::from_str("JaneDoe").unwrap(),
+        }
+    );
+    assert_eq!(ResourceName::from(typed).to_string(), arn.to_string());
+}
+
+#[test]
+fn test_role_with_path() {
+    let arn = iam::role_with_path(
+        Partition::Aws.into(),
+        account(),
+        &[
+            Identifier::from_str("service-role").unwrap(),
+            Identifier::from_str("team").unwrap(),
+        ],
+        Identifier::from_str("my-role").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:iam::123456789012:role/service-role/team/my-role"
+    );
+    match IamResource::try_from(&arn).unwrap() {
+        IamResource::Role { path, name, .. } => {
+            assert_eq!(path.len(), 2);
+            assert_eq!(name.to_string(), "my-role");
+        }
+        other => panic!("expected a role, found {:?}", other),
+    }
+}
+
+#[test]
+fn test_service_linked_role() {
+    let arn = iam::service_linked_role(
+        Partition::Aws.into(),
+        account(),
+        Identifier::from_str("elasticbeanstalk.amazonaws.com").unwrap(),
+        Identifier::from_str("AWSServiceRoleForElasticBeanstalk").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:iam::123456789012:role/aws-service-role/elasticbeanstalk.amazonaws.com/AWSServiceRoleForElasticBeanstalk"
+    );
+    let typed = IamResource::try_from(&arn).unwrap();
+    assert!(matches!(typed, IamResource::ServiceLinkedRole { .. }));
+    assert_eq!(ResourceName::from(typed), arn);
+}
+
+#[test]
+fn test_other_resource_types() {
+    let arns = [
+        "arn:aws:iam::123456789012:instance-profile/Webserver",
+        "arn:aws:iam::123456789012:instance-profile/web/Webserver",
+        "arn:aws:iam::123456789012:server-certificate/division_abc/ProdServerCert",
+        "arn:aws:iam::123456789012:mfa/JaneMFA",
+        "arn:aws:iam::123456789012:saml-provider/ADFSProvider",
+        "arn:aws:iam::123456789012:oidc-provider/oidc.eks.us-west-2.amazonaws.com/id/EXAMPLED539D4633E53DE1B71EXAMPLE",
+        "arn:aws:iam::123456789012:access-report/o-a1b2c3d4e5/r-f6g7h8i9j0example/ou-1a2b3c-k9l8m7n6o5example",
+    ];
+    for s in arns.iter() {
+        let arn = ResourceName::from_str(s).unwrap();
+        let typed = IamResource::try_from(&arn).unwrap();
+        assert_eq!(ResourceName::from(typed).to_string(), *s);
+    }
+
+    let arn = iam::instance_profile_with_path(
+        Partition::Aws.into(),
+        account(),
+        &[Identifier::from_str("web").unwrap()],
+        Identifier::from_str("Webserver").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(arn.to_string(), arns[1]);
+
+    let arn = iam::oidc_provider(
+        Partition::Aws.into(),
+        account(),
+        ResourceIdentifier::from_str(
+            "oidc.eks.us-west-2.amazonaws.com/id/EXAMPLED539D4633E53DE1B71EXAMPLE",
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(arn.to_string(), arns[5]);
+
+    let arn = iam::access_report(
+        Partition::Aws.into(),
+        account(),
+        ResourceIdentifier::from_str(
+            "o-a1b2c3d4e5/r-f6g7h8i9j0example/ou-1a2b3c-k9l8m7n6o5example",
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(arn.to_string(), arns[6]);
+}
+
+#[test]
+fn test_unsupported_type() {
+    let arn = ResourceName::from_str("arn:aws:iam::123456789012:unknown-type/Webserver").unwrap();
+    assert_eq!(
+        IamResource::try_from(&arn),
+        Err(Error::InvalidResource("unknown-type/Webserver".to_string()))
+    );
+    let arn =
+        ResourceName::from_str("arn:aws:iam::123456789012:saml-provider/a/ADFSProvider").unwrap();
+    assert!(IamResource::try_from(&arn).is_err());
+}
+
+#[test]
+fn test_invalid_names() {
+    assert_eq!(
+        iam::user(
+            Partition::Aws.into(),
+            account(),
+            Identifier::from_str("Jane#Doe").unwrap(),
+        ),
+        Err(Error::InvalidIdentifier("Jane#Doe".to_string()))
+    );
+    let long_name = "r".repeat(65);
+    assert_eq!(
+        iam::role(
+            Partition::Aws.into(),
+            account(),
+            Identifier::from_str(&long_name).unwrap(),
+        ),
+        Err(Error::InvalidIdentifier(long_name))
+    );
+    assert!(iam::group(
+        Partition::Aws.into(),
+        account(),
+        Identifier::from_str(&"g".repeat(128)).unwrap(),
+    )
+    .is_ok());
+    assert!(iam::policy(
+        Partition::Aws.into(),
+        account(),
+        Identifier::from_str("Admin*").unwrap(),
+    )
+    .is_ok());
+    assert!(iam::oidc_provider(
+        Partition::Aws.into(),
+        account(),
+        ResourceIdentifier::from_str("https://token.actions.githubusercontent.com").unwrap(),
+    )
+    .is_err());
+    for entity_path in [
+        "r-f6g7h8i9j0example",
+        "o-a1b2c3d4e5/ou-1a2b3c-k9l8m7n6o5example",
+        "o-a1b2c3d4e5/r-f6g7h8i9j0example/123456789012/ou-1a2b3c-k9l8m7n6o5example",
+        "o-A1B2C3D4E5/r-f6g7h8i9j0example",
+    ] {
+        assert_eq!(
+            iam::access_report(
+                Partition::Aws.into(),
+                account(),
+                ResourceIdentifier::from_str(entity_path).unwrap(),
+            ),
+            Err(Error::InvalidResource(entity_path.to_string()))
+        );
+    }
+    assert!(iam::access_report(
+        Partition::Aws.into(),
+        account(),
+        ResourceIdentifier::from_str("o-a1b2c3d4e5/r-f6g7h8i9j0example/123456789012").unwrap(),
+    )
+    .is_ok());
+    assert!(iam::user_with_path(
+        Partition::Aws.into(),
+        account(),
+        &[Identifier::from_str(&"p".repeat(511)).unwrap()],
+        Identifier::from_str("JaneDoe").unwrap(),
+    )
+    .is_err());
+}
@@ -0,0 +1,135 @@
+use aws_arn::builder::kms::{self, KmsResource};
+use aws_arn::builder::ServiceResource;
+use aws_arn::known::{Partition, Region};
+use aws_arn::{Error, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::{account, id, resource_id};
+
+const KEY_ID: &str = "1234abcd-12ab-34cd-56ef-1234567890ab";
+
+const MRK_ID: &str = "mrk-1234abcd12ab34cd56ef1234567890ab";
+
+fn key(key_id: &str) -> Result<ResourceName, Error> {
+    kms::key(
+        Partition::Aws.into(),
+        Region::UsWest2.into(),
+        account(),
+        id(key_id),
+    )
+}
+
+fn from_key_id(key_id: &str) -> Result<ResourceName, Error> {
+    kms::from_key_id(
+        Partition::Aws.into(),
+        Region::UsWest2.into(),
+        account(),
+        key_id,
+    )
+}
+
+#[test]
+fn test_key_round_trip() {
+    let arn = key(KEY_ID).unwrap();
+    assert_eq!(
+        arn.to_string(),
+        format!("arn:aws:kms:us-west-2:123456789012:key/{}", KEY_ID)
+    );
+    let typed = KmsResource::try_from(&arn).unwrap();
+    assert_eq!(typed.resource_type_name(), "key");
+    assert!(!typed.is_multi_region());
+    assert_eq!(typed.to_arn(), arn);
+}
+
+#[test]
+fn test_multi_region_key() {
+    let arn = key(MRK_ID).unwrap();
+    assert_eq!(
+        arn.to_string(),
+        format!("arn:aws:kms:us-west-2:123456789012:key/{}", MRK_ID)
+    );
+    assert!(KmsResource::try_from(&arn).unwrap().is_multi_region());
+    let replica = ResourceName::from_str(&format!(
+        "arn:aws:kms:eu-west-1:123456789012:key/{}",
+        MRK_ID
+    ))
+    .unwrap();
+    assert!(KmsResource::try_from(&replica).unwrap().is_multi_region());
+}
+
+#[test]
+fn test_invalid_key_ids() {
+    for key_id in [
+        "1234abcd",
+        "mrk-1234",
+        "mrk-1234abcd-12ab-34cd-56ef-1234567890ab",
+        "1234abcd-12ab-34cd-56ef-1234567890zz",
+    ] {
+        assert_eq!(
+            key(key_id),
+            Err(Error::InvalidIdentifier(key_id.to_string()))
+        );
+    }
+    assert!(key("*").is_ok());
+}
+
+#[test]
+fn test_alias_round_trip() {
+    let arn = kms::alias(
+        Partition::Aws.into(),
+        Region::UsWest2.into(),
+        account(),
+        resource_id("aws/s3"),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:kms:us-west-2:123456789012:alias/aws/s3"
+    );
+    let typed = KmsResource::try_from(&arn).unwrap();
+    assert_eq!(typed.resource_type_name(), "alias");
+    assert!(!typed.is_multi_region());
+    assert_eq!(typed.to_arn(), arn);
+    assert!(kms::alias(
+        Partition::Aws.into(),
+        Region::UsWest2.into(),
+        account(),
+        resource_id("my.alias"),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_from_key_id() {
+    assert_eq!(from_key_id(KEY_ID), key(KEY_ID));
+    assert_eq!(from_key_id(MRK_ID), key(MRK_ID));
+    assert_eq!(
+        from_key_id("alias/ExampleAlias").unwrap().to_string(),
+        "arn:aws:kms:us-west-2:123456789012:alias/ExampleAlias"
+    );
+    let other = format!("arn:aws:kms:us-east-2:444455556666:key/{}", KEY_ID);
+    assert_eq!(from_key_id(&other).unwrap().to_string(), other);
+    let other = "arn:aws:kms:us-east-2:444455556666:alias/ExampleAlias";
+    assert_eq!(from_key_id(other).unwrap().to_string(), other);
+    assert!(from_key_id("ExampleAlias").is_err());
+    assert_eq!(
+        from_key_id("arn:aws:kms:us-east-2:444455556666:key/1234"),
+        Err(Error::InvalidIdentifier("1234".to_string()))
+    );
+    assert_eq!(
+        from_key_id("arn:aws:kms:us-east-2:444455556666:alias/my.alias"),
+        Err(Error::InvalidIdentifier("my.alias".to_string()))
+    );
+    let pattern = "arn:aws:kms:us-east-2:444455556666:key/*";
+    assert_eq!(from_key_id(pattern).unwrap().to_string(), pattern);
+    assert!(matches!(
+        from_key_id("arn:aws:sqs:us-east-2:444455556666:my-queue"),
+        Err(Error::ResourceMismatch { .. })
+    ));
+    assert_eq!(
+        from_key_id("arn:aws:kms:us-east-2:444455556666:grant/1234"),
+        Err(Error::InvalidResource("grant/1234".to_string()))
+    );
+}
@@ -0,0 +1,236 @@
+use aws_arn::builder::lambda::{self, LambdaQualifier, LambdaResource};
+use aws_arn::known::{Partition, Region};
+use aws_arn::{Error, Identifier, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::account;
+
+#[test]
+fn test_function_round_trip() {
+    let arn = lambda::function(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        Identifier::from_str("my-function").unwrap(),
+    )
+    .unwrap();
+    let typed = LambdaResource::try_from(&arn).unwrap();
+    assert_eq!(
+        typed,
+        LambdaResource::Function {
+            partition: Partition::Aws.into(),
+            region: Region::UsEast2.into(),
+            account: account(),
+            name: Identifier::from_str("my-function").unwrap(),
+            qualifier: None,
+        }
+    );
+    assert_eq!(ResourceName::from(typed), arn);
+}
+
+#[test]
+fn test_layer_version_round_trip() {
+    let arn = lambda::layer_version(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        Identifier::from_str("my-layer").unwrap(),
+        3,
+    )
+    .unwrap();
+    let typed = LambdaResource::try_from(&arn).unwrap();
+    assert!(matches!(
+        typed,
+        LambdaResource::LayerVersion { version: 3, .. }
+    ));
+    assert_eq!(ResourceName::from(typed), arn);
+    assert_eq!(
+        lambda::layer_version(
+            Partition::Aws.into(),
+            Region::UsEast2.into(),
+            account(),
+            Identifier::from_str("my-layer").unwrap(),
+            0,
+        ),
+        Err(Error::InvalidIdentifier("0".to_string()))
+    );
+    for version in ["0", "03", "+3", "-3"] {
+        let arn = ResourceName::from_str(&format!(
+            "arn:aws:lambda:us-east-2:123456789012:layer:my-layer:{}",
+            version
+        ))
+        .unwrap();
+        assert!(LambdaResource::try_from(&arn).is_err(), "{}", version);
+    }
+}
+
+#[test]
+fn test_parse_qualified_function() {
+    let arn =
+        ResourceName::from_str("arn:aws:lambda:us-east-2:123456789012:function:my-function:PROD")
+            .unwrap();
+    match LambdaResource::try_from(&arn).unwrap() {
+        LambdaResource::Function { qualifier, .. } => {
+            assert_eq!(
+                qualifier,
+                Some(LambdaQualifier::Alias(
+                    Identifier::from_str("PROD").unwrap()
+                ))
+            )
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_qualifiers() {
+    assert_eq!(
+        LambdaQualifier::from_str("$LATEST"),
+        Ok(LambdaQualifier::Latest)
+    );
+    assert_eq!(
+        LambdaQualifier::from_str("42"),
+        Ok(LambdaQualifier::Version(42))
+    );
+    assert_eq!(
+        LambdaQualifier::from_str("live-2"),
+        Ok(LambdaQualifier::Alias(
+            Identifier::from_str("live-2").unwrap()
+        ))
+    );
+    assert!(LambdaQualifier::from_str("$PROD").is_err());
+    assert!(LambdaQualifier::from_str("99999999999").is_err());
+    assert!(LambdaQualifier::from_str("0").is_err());
+    assert!(LambdaQualifier::from_str("00").is_err());
+    assert!(LambdaQualifier::from_str("07").is_err());
+    assert_eq!(LambdaQualifier::Latest.to_string(), "$LATEST");
+
+    let arn = lambda::qualified_function(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        Identifier::from_str("my-function").unwrap(),
+        LambdaQualifier::Version(7),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:lambda:us-east-2:123456789012:function:my-function:7"
+    );
+    match LambdaResource::try_from(&arn).unwrap() {
+        LambdaResource::Function { qualifier, .. } => {
+            assert_eq!(qualifier, Some(LambdaQualifier::Version(7)))
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(
+        lambda::unqualified_function_from(&arn).unwrap().to_string(),
+        "arn:aws:lambda:us-east-2:123456789012:function:my-function"
+    );
+
+    let arn =
+        ResourceName::from_str("arn:aws:lambda:us-east-2:123456789012:layer:my-layer").unwrap();
+    assert_eq!(
+        lambda::unqualified_function_from(&arn),
+        Err(Error::ResourceMismatch {
+            expected: "function".to_string(),
+            found: "layer".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_function_from_partial() {
+    let partial = |s: &str| {
+        lambda::function_from_partial(Partition::Aws.into(), Region::UsWest2.into(), account(), s)
+            .map(|arn| arn.to_string())
+    };
+    assert_eq!(
+        partial("my-function"),
+        Ok("arn:aws:lambda:us-west-2:123456789012:function:my-function".to_string())
+    );
+    assert_eq!(
+        partial("my-function:$LATEST"),
+        Ok("arn:aws:lambda:us-west-2:123456789012:function:my-function:$LATEST".to_string())
+    );
+    assert_eq!(
+        partial("function:my-function"),
+        Ok("arn:aws:lambda:us-west-2:123456789012:function:my-function".to_string())
+    );
+    assert_eq!(
+        partial("function:my-function:PROD"),
+        Ok("arn:aws:lambda:us-west-2:123456789012:function:my-function:PROD".to_string())
+    );
+    assert_eq!(
+        partial("210987654321:function:my-function"),
+        Ok("arn:aws:lambda:us-west-2:210987654321:function:my-function".to_string())
+    );
+    assert_eq!(
+        partial("210987654321:function:my-function:PROD"),
+        Ok("arn:aws:lambda:us-west-2:210987654321:function:my-function:PROD".to_string())
+    );
+    assert_eq!(
+        partial("arn:aws:lambda:eu-west-1:123456789012:function:my-function:3"),
+        Ok("arn:aws:lambda:eu-west-1:123456789012:function:my-function:3".to_string())
+    );
+    assert!(partial("arn:aws:lambda:eu-west-1:123456789012:layer:my-layer").is_err());
+    assert!(partial("210987654321:layer:my-layer").is_err());
+    assert!(partial("my.function").is_err());
+}
+
+#[test]
+fn test_parse_errors() {
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap();
+    assert_eq!(
+        LambdaResource::try_from(&arn),
+        Err(Error::ResourceMismatch {
+            expected: "lambda".to_string(),
+            found: "s3".to_string(),
+        })
+    );
+
+    let arn = ResourceName::from_str("arn:aws:lambda:us-east-2::function:my-function").unwrap();
+    assert_eq!(LambdaResource::try_from(&arn), Err(Error::MissingAccountId));
+
+    let arn =
+        ResourceName::from_str("arn:aws:lambda:us-east-2:123456789012:layer:my-layer:x").unwrap();
+    assert!(LambdaResource::try_from(&arn).is_err());
+}
+
+#[test]
+fn test_invalid_names() {
+    assert_eq!(
+        lambda::function(
+            Partition::Aws.into(),
+            Region::UsEast2.into(),
+            account(),
+            Identifier::from_str("my.function").unwrap(),
+        ),
+        Err(Error::InvalidIdentifier("my.function".to_string()))
+    );
+    assert!(lambda::function(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        Identifier::from_str("my-*").unwrap(),
+    )
+    .is_ok());
+    assert_eq!(
+        lambda::event_source_mapping(
+            Partition::Aws.into(),
+            Region::UsEast2.into(),
+            account(),
+            Identifier::from_str("not-a-uuid").unwrap(),
+        ),
+        Err(Error::InvalidIdentifier("not-a-uuid".to_string()))
+    );
+    assert!(lambda::event_source_mapping(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        Identifier::from_str("14e0db71-5d35-4eb5-b481-8945cf9d10c2").unwrap(),
+    )
+    .is_ok());
+}
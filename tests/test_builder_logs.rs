@@ -0,0 +1,141 @@
+use aws_arn::builder::logs::{self, LogsResource};
+use aws_arn::builder::{lambda, ServiceResource};
+use aws_arn::known::{Partition, Region};
+use aws_arn::{Error, Identifier, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::{account, resource_id as name};
+
+fn log_group(group: &str) -> ResourceName {
+    logs::log_group(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        name(group),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_log_group_round_trip() {
+    let arn = log_group("/aws/ecs/my-service");
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:logs:us-east-1:123456789012:log-group:/aws/ecs/my-service"
+    );
+    let typed = LogsResource::try_from(&arn).unwrap();
+    assert_eq!(typed.resource_type_name(), "log-group");
+    assert_eq!(typed.to_arn(), arn);
+    assert!(logs::log_group(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        name("my:group"),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_log_group_suffix_normalized() {
+    let pattern = logs::log_group_pattern(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        name("/aws/ecs/my-service"),
+    )
+    .unwrap();
+    assert_eq!(
+        pattern.to_string(),
+        "arn:aws:logs:us-east-1:123456789012:log-group:/aws/ecs/my-service:*"
+    );
+    let plain = log_group("/aws/ecs/my-service");
+    let typed = LogsResource::try_from(&pattern).unwrap();
+    assert_eq!(
+        typed,
+        LogsResource::LogGroup {
+            partition: Partition::Aws.into(),
+            region: Region::UsEast1.into(),
+            account: account(),
+            name: name("/aws/ecs/my-service"),
+            any_stream: true,
+        }
+    );
+    assert_eq!(typed.resource_type_name(), "log-group");
+    assert_eq!(typed.to_arn(), pattern);
+    let plain_typed = LogsResource::try_from(&plain).unwrap();
+    assert!(matches!(
+        plain_typed,
+        LogsResource::LogGroup {
+            any_stream: false,
+            ..
+        }
+    ));
+    assert_eq!(typed, plain_typed);
+    assert_eq!(plain_typed.to_arn(), plain);
+    assert_eq!(logs::log_group_from(&pattern), Ok(plain.clone()));
+    assert_eq!(logs::log_group_from(&plain), Ok(plain));
+}
+
+#[test]
+fn test_log_stream_round_trip() {
+    let arn = logs::log_stream(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        name("/aws/lambda/my-function"),
+        name("2024/01/01/[$LATEST]0123456789abcdef"),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/my-function:log-stream:2024/01/01/[$LATEST]0123456789abcdef"
+    );
+    let typed = LogsResource::try_from(&arn).unwrap();
+    assert_eq!(typed.resource_type_name(), "log-stream");
+    assert_eq!(typed.to_arn(), arn);
+    assert_eq!(
+        logs::log_group_from(&arn),
+        Ok(log_group("/aws/lambda/my-function"))
+    );
+}
+
+#[test]
+fn test_function_log_group() {
+    let function = lambda::qualified_function(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        Identifier::from_str("my-function").unwrap(),
+        lambda::LambdaQualifier::Version(3),
+    )
+    .unwrap();
+    assert_eq!(
+        logs::function_log_group_from(&function),
+        Ok(log_group("/aws/lambda/my-function"))
+    );
+    let layer =
+        ResourceName::from_str("arn:aws:lambda:us-east-1:123456789012:layer:my-layer").unwrap();
+    assert!(matches!(
+        logs::function_log_group_from(&layer),
+        Err(Error::ResourceMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_parse_errors() {
+    let arn =
+        ResourceName::from_str("arn:aws:logs:us-east-1:123456789012:destination:my-destination")
+            .unwrap();
+    assert_eq!(
+        LogsResource::try_from(&arn),
+        Err(Error::InvalidResource(
+            "destination:my-destination".to_string()
+        ))
+    );
+    let arn =
+        ResourceName::from_str("arn:aws:logs:us-east-1:123456789012:log-group:my-group:log-stream")
+            .unwrap();
+    assert!(LogsResource::try_from(&arn).is_err());
+}
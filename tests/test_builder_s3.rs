@@ -0,0 +1,462 @@
+use aws_arn::builder::s3::{self, BucketName, ObjectKey, S3Resource};
+use aws_arn::builder::ServiceResource;
+use aws_arn::known::{Partition, Region};
+use aws_arn::{AccountIdentifier, Error, Identifier, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::account;
+
+#[test]
+fn test_bucket_round_trip() {
+    let arn = s3::bucket(BucketName::from_str("my-bucket").unwrap()).unwrap();
+    let typed = S3Resource::try_from(&arn).unwrap();
+    assert_eq!(
+        typed,
+        S3Resource::Bucket {
+            partition: Partition::Aws.into(),
+            bucket: BucketName::from_str("my-bucket").unwrap(),
+        }
+    );
+    assert_eq!(ResourceName::from(typed), arn);
+}
+
+#[test]
+fn test_object_with_path_key() {
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/photos/cat.jpg").unwrap();
+    let typed = S3Resource::try_from(&arn).unwrap();
+    assert_eq!(
+        typed,
+        S3Resource::Object {
+            partition: Partition::Aws.into(),
+            bucket: BucketName::from_str("my-bucket").unwrap(),
+            key: ObjectKey::from_str("photos/cat.jpg").unwrap(),
+        }
+    );
+    assert_eq!(ResourceName::from(typed).to_string(), arn.to_string());
+}
+
+#[test]
+fn test_job_round_trip() {
+    let arn = s3::job(
+        Region::UsEast1.into(),
+        AccountIdentifier::from_str("123456789012").unwrap(),
+        Identifier::from_str("23476").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3:us-east-1:123456789012:job/23476"
+    );
+    let typed = S3Resource::try_from(&arn).unwrap();
+    assert!(matches!(typed, S3Resource::Job { .. }));
+    assert_eq!(ResourceName::from(typed), arn);
+}
+
+#[test]
+fn test_bucket_with_region() {
+    let arn = ResourceName::from_str("arn:aws:s3:us-east-1::my-bucket").unwrap();
+    assert_eq!(S3Resource::try_from(&arn), Err(Error::RegionNotAllowed));
+}
+
+#[test]
+fn test_object_from_bucket() {
+    let bucket = s3::bucket(BucketName::from_str("my-bucket").unwrap()).unwrap();
+    let arn = s3::object_from(&bucket, ObjectKey::from_str("cat.jpg").unwrap()).unwrap();
+    assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket/cat.jpg");
+}
+
+#[test]
+fn test_object_from_mismatch() {
+    let queue = ResourceName::from_str("arn:aws:sqs:us-east-2:123456789012:my-queue").unwrap();
+    assert_eq!(
+        s3::object_from(&queue, ObjectKey::from_str("cat.jpg").unwrap()),
+        Err(Error::ResourceMismatch {
+            expected: "s3 | s3-object-lambda | s3-outposts | s3express".to_string(),
+            found: "sqs".to_string(),
+        })
+    );
+
+    let object = ResourceName::from_str("arn:aws:s3:::my-bucket/cat.jpg").unwrap();
+    assert_eq!(
+        s3::object_from(&object, ObjectKey::from_str("dog.jpg").unwrap()),
+        Err(Error::ResourceMismatch {
+            expected: "bucket".to_string(),
+            found: "object".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_bucket_names() {
+    for name in &[
+        "my-bucket",
+        "my.dotted.bucket",
+        "abc",
+        "123bucket",
+        "my-base--usw2-az1--x-s3",
+        "my-*",
+    ] {
+        assert!(BucketName::from_str(name).is_ok(), "{}", name);
+    }
+    for name in &[
+        "My-Bucket",
+        "ab",
+        "-my-bucket",
+        "my-bucket.",
+        "my_bucket",
+        "my..bucket",
+        "192.168.0.1",
+        "xn--my-bucket",
+        "sthree-bucket",
+        "my-bucket-s3alias",
+        "my-bucket--ol-s3",
+        "my-bucket.mrap",
+        "my-bucket--x-s3",
+        "my.base--usw2-az1--x-s3",
+        &"a".repeat(64),
+    ] {
+        assert_eq!(
+            BucketName::from_str(name),
+            Err(Error::InvalidIdentifier(name.to_string()))
+        );
+    }
+    assert!(BucketName::from_str("my-base--usw2-az1--x-s3")
+        .unwrap()
+        .is_directory_bucket());
+
+    let directory = BucketName::from_str("my-base--usw2-az1--x-s3").unwrap();
+    assert_eq!(
+        s3::bucket(directory),
+        Err(Error::InvalidIdentifier(
+            "my-base--usw2-az1--x-s3".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_object_keys() {
+    assert!(ObjectKey::from_str("photos/2024/cat one.jpg").is_ok());
+    assert!(ObjectKey::from_str(&"k".repeat(1024)).is_ok());
+    assert!(ObjectKey::from_str(&"k".repeat(1025)).is_err());
+    assert!(ObjectKey::from_str("").is_err());
+    assert_eq!(
+        ObjectKey::from_str("caf\u{e9}.jpg"),
+        Err(Error::InvalidResource("caf\u{e9}.jpg".to_string()))
+    );
+}
+
+fn round_trip(s: &str) -> S3Resource {
+    let arn = ResourceName::from_str(s).unwrap();
+    let typed = S3Resource::try_from(&arn).unwrap();
+    assert_eq!(ResourceName::from(&typed).to_string(), s);
+    typed
+}
+
+#[test]
+fn test_access_points() {
+    let arn = s3::access_point(
+        Region::UsWest2.into(),
+        account(),
+        Identifier::from_str("finance-ap").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3:us-west-2:123456789012:accesspoint/finance-ap"
+    );
+    assert!(matches!(
+        round_trip(&arn.to_string()),
+        S3Resource::AccessPoint { .. }
+    ));
+
+    let arn = s3::access_point_object(
+        Region::UsWest2.into(),
+        account(),
+        Identifier::from_str("finance-ap").unwrap(),
+        ObjectKey::from_str("report.csv").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3:us-west-2:123456789012:accesspoint/finance-ap/object/report.csv"
+    );
+    match round_trip("arn:aws:s3:us-west-2:123456789012:accesspoint/finance-ap/object/2024/q1.csv")
+    {
+        S3Resource::AccessPointObject { name, key, .. } => {
+            assert_eq!(name, Identifier::from_str("finance-ap").unwrap());
+            assert_eq!(key, ObjectKey::from_str("2024/q1.csv").unwrap());
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    assert_eq!(
+        s3::access_point(
+            Region::UsWest2.into(),
+            account(),
+            Identifier::from_str("Finance_AP").unwrap(),
+        ),
+        Err(Error::InvalidIdentifier("Finance_AP".to_string()))
+    );
+}
+
+#[test]
+fn test_multi_region_access_point() {
+    let arn = s3::multi_region_access_point(
+        account(),
+        Identifier::from_str("mfzwi23gnjvgw.mrap").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap"
+    );
+    let typed = round_trip(&arn.to_string());
+    assert!(matches!(typed, S3Resource::MultiRegionAccessPoint { .. }));
+    assert_eq!(typed.service().to_string(), "s3");
+
+    assert_eq!(
+        s3::multi_region_access_point(account(), Identifier::from_str("mfzwi23gnjvgw").unwrap()),
+        Err(Error::InvalidIdentifier("mfzwi23gnjvgw".to_string()))
+    );
+}
+
+#[test]
+fn test_object_lambda_access_point() {
+    let arn = s3::object_lambda_access_point(
+        Region::UsEast1.into(),
+        account(),
+        Identifier::from_str("redacting-ap").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3-object-lambda:us-east-1:123456789012:accesspoint/redacting-ap"
+    );
+    let typed = round_trip(&arn.to_string());
+    assert!(matches!(typed, S3Resource::ObjectLambdaAccessPoint { .. }));
+    assert_eq!(typed.service().to_string(), "s3-object-lambda");
+}
+
+#[test]
+fn test_storage_lens() {
+    let arn = s3::storage_lens(
+        Region::UsEast1.into(),
+        account(),
+        Identifier::from_str("default-account-dashboard").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3:us-east-1:123456789012:storage-lens/default-account-dashboard"
+    );
+    assert!(matches!(
+        round_trip(&arn.to_string()),
+        S3Resource::StorageLens { .. }
+    ));
+}
+
+#[test]
+fn test_outposts() {
+    let outpost_id = Identifier::from_str("op-01ac5d28a6a232904").unwrap();
+    let arn = s3::outpost_access_point(
+        Region::UsWest2.into(),
+        account(),
+        outpost_id.clone(),
+        Identifier::from_str("my-ap").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-01ac5d28a6a232904/accesspoint/my-ap"
+    );
+    let typed = round_trip(&arn.to_string());
+    assert!(matches!(typed, S3Resource::OutpostAccessPoint { .. }));
+    assert_eq!(typed.service().to_string(), "s3-outposts");
+
+    let arn = s3::outpost_endpoint(
+        Region::UsWest2.into(),
+        account(),
+        outpost_id,
+        Identifier::from_str("ec2ed1a9b8c7d6e5").unwrap(),
+    )
+    .unwrap();
+    assert!(matches!(
+        round_trip(&arn.to_string()),
+        S3Resource::OutpostEndpoint { .. }
+    ));
+
+    assert_eq!(
+        s3::outpost_endpoint(
+            Region::UsWest2.into(),
+            account(),
+            Identifier::from_str("01ac5d28a6a232904").unwrap(),
+            Identifier::from_str("ec2ed1a9b8c7d6e5").unwrap(),
+        ),
+        Err(Error::InvalidIdentifier("01ac5d28a6a232904".to_string()))
+    );
+}
+
+#[test]
+fn test_directory_bucket() {
+    let arn = s3::directory_bucket(
+        Region::UsWest2.into(),
+        account(),
+        BucketName::from_str("my-base--usw2-az1--x-s3").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3express:us-west-2:123456789012:bucket/my-base--usw2-az1--x-s3"
+    );
+    let typed = round_trip(&arn.to_string());
+    assert!(matches!(typed, S3Resource::DirectoryBucket { .. }));
+    assert_eq!(typed.service().to_string(), "s3express");
+
+    assert_eq!(
+        s3::directory_bucket(
+            Region::UsWest2.into(),
+            account(),
+            BucketName::from_str("my-bucket").unwrap(),
+        ),
+        Err(Error::InvalidIdentifier("my-bucket".to_string()))
+    );
+}
+
+#[test]
+fn test_bucket_named_like_resource_type() {
+    assert!(matches!(
+        round_trip("arn:aws:s3:::accesspoint/photos/cat.jpg"),
+        S3Resource::Object { .. }
+    ));
+}
+
+#[test]
+fn test_uri_round_trip() {
+    let arn = s3::from_uri("s3://my-bucket/photos/2024/cat.jpg").unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3:::my-bucket/photos/2024/cat.jpg"
+    );
+    assert_eq!(
+        s3::to_uri(&arn).unwrap(),
+        "s3://my-bucket/photos/2024/cat.jpg"
+    );
+
+    let arn = s3::from_uri("s3a://my-bucket/").unwrap();
+    assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket");
+    assert_eq!(s3::to_uri(&arn).unwrap(), "s3://my-bucket");
+
+    assert_eq!(
+        s3::from_uri("https://my-bucket"),
+        Err(Error::InvalidUrl("https://my-bucket".to_string()))
+    );
+    assert_eq!(
+        s3::from_uri("s3://My_Bucket/key"),
+        Err(Error::InvalidUrl("s3://My_Bucket/key".to_string()))
+    );
+}
+
+#[test]
+fn test_from_url() {
+    for url in &[
+        "https://my-bucket.s3.us-east-1.amazonaws.com/photos/cat%20one.jpg",
+        "https://my-bucket.s3.amazonaws.com/photos/cat%20one.jpg",
+        "https://my-bucket.s3-us-west-2.amazonaws.com/photos/cat%20one.jpg?versionId=1",
+        "https://s3.us-east-1.amazonaws.com/my-bucket/photos/cat%20one.jpg",
+        "https://s3.dualstack.eu-west-1.amazonaws.com/my-bucket/photos/cat%20one.jpg",
+    ] {
+        assert_eq!(
+            s3::from_url(url).unwrap().to_string(),
+            "arn:aws:s3:::my-bucket/photos/cat one.jpg",
+            "{}",
+            url
+        );
+    }
+    assert_eq!(
+        s3::from_url("https://my.dotted.bucket.s3.us-east-1.amazonaws.com/")
+            .unwrap()
+            .to_string(),
+        "arn:aws:s3:::my.dotted.bucket"
+    );
+    assert_eq!(
+        s3::from_url("https://s3.cn-north-1.amazonaws.com.cn/my-bucket")
+            .unwrap()
+            .to_string(),
+        "arn:aws-cn:s3:::my-bucket"
+    );
+    assert_eq!(
+        s3::from_url("https://my-bucket.s3.us-gov-west-1.amazonaws.com/key")
+            .unwrap()
+            .to_string(),
+        "arn:aws-us-gov:s3:::my-bucket/key"
+    );
+    assert_eq!(
+        s3::from_url(
+            "https://finance-ap-123456789012.s3-accesspoint.us-west-2.amazonaws.com/q1.csv"
+        )
+        .unwrap()
+        .to_string(),
+        "arn:aws:s3:us-west-2:123456789012:accesspoint/finance-ap/object/q1.csv"
+    );
+    for url in &[
+        "https://example.com/my-bucket",
+        "ftp://s3.amazonaws.com/my-bucket",
+        "https://s3.amazonaws.com/my-bucket/bad%2",
+        "https://mfzwi23gnjvgw.mrap.accesspoint.s3-global.amazonaws.com/key",
+    ] {
+        assert_eq!(s3::from_url(url), Err(Error::InvalidUrl(url.to_string())));
+    }
+}
+
+#[test]
+fn test_to_url() {
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/photos/cat one+two.jpg").unwrap();
+    assert_eq!(
+        s3::to_url(
+            &arn,
+            Some(Region::UsEast1.into()),
+            s3::UrlStyle::VirtualHosted
+        )
+        .unwrap(),
+        "https://my-bucket.s3.us-east-1.amazonaws.com/photos/cat%20one%2Btwo.jpg"
+    );
+    assert_eq!(
+        s3::to_url(&arn, None, s3::UrlStyle::Path).unwrap(),
+        "https://s3.amazonaws.com/my-bucket/photos/cat%20one%2Btwo.jpg"
+    );
+    assert_eq!(
+        s3::from_url(&s3::to_url(&arn, None, s3::UrlStyle::default()).unwrap()).unwrap(),
+        arn
+    );
+
+    let arn = ResourceName::from_str("arn:aws-cn:s3:::my-bucket").unwrap();
+    assert_eq!(
+        s3::to_url(
+            &arn,
+            Some(Identifier::from_str("cn-north-1").unwrap()),
+            s3::UrlStyle::Path
+        )
+        .unwrap(),
+        "https://s3.cn-north-1.amazonaws.com.cn/my-bucket"
+    );
+
+    let arn = ResourceName::from_str(
+        "arn:aws:s3:us-west-2:123456789012:accesspoint/finance-ap/object/q1.csv",
+    )
+    .unwrap();
+    assert_eq!(
+        s3::to_url(&arn, None, s3::UrlStyle::Path).unwrap(),
+        "https://finance-ap-123456789012.s3-accesspoint.us-west-2.amazonaws.com/q1.csv"
+    );
+
+    let arn = ResourceName::from_str("arn:aws:s3:us-east-1:123456789012:job/23476").unwrap();
+    assert_eq!(
+        s3::to_url(&arn, None, s3::UrlStyle::Path),
+        Err(Error::ResourceMismatch {
+            expected: "bucket".to_string(),
+            found: "job".to_string(),
+        })
+    );
+}
@@ -0,0 +1,158 @@
+use aws_arn::builder::secretsmanager::{self, SecretsManagerResource};
+use aws_arn::builder::{KnownResource, ServiceResource};
+use aws_arn::known::{Partition, Region};
+use aws_arn::{Error, Identifier, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::{account, parse, resource_id as name};
+
+#[test]
+fn test_secret_round_trip() {
+    let arn = secretsmanager::secret(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        name("prod/my-secret"),
+        Identifier::from_str("AbCdEf").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:secretsmanager:us-east-1:123456789012:secret:prod/my-secret-AbCdEf"
+    );
+    let typed = SecretsManagerResource::from_complete(&arn).unwrap();
+    assert_eq!(
+        typed,
+        SecretsManagerResource::Secret {
+            partition: Partition::Aws.into(),
+            region: Region::UsEast1.into(),
+            account: account(),
+            name: name("prod/my-secret"),
+            suffix: Some(Identifier::from_str("AbCdEf").unwrap()),
+        }
+    );
+    assert_eq!(typed.resource_type_name(), "secret");
+    assert_eq!(typed.to_arn(), arn);
+    let typed = SecretsManagerResource::try_from(&arn).unwrap();
+    assert!(matches!(
+        &typed,
+        SecretsManagerResource::Secret { name: n, suffix: None, .. }
+            if n.to_string() == "prod/my-secret-AbCdEf"
+    ));
+    assert_eq!(typed.to_arn(), arn);
+    assert!(secretsmanager::secret(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        name("my-secret"),
+        Identifier::from_str("AbCd").unwrap(),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_matches_name() {
+    let arn = ResourceName::from_str(
+        "arn:aws:secretsmanager:us-east-1:123456789012:secret:my-secret-AbCdEf",
+    )
+    .unwrap();
+    let typed = SecretsManagerResource::from_complete(&arn).unwrap();
+    assert!(typed.matches_name("my-secret"));
+    assert!(!typed.matches_name("my"));
+    assert!(!typed.matches_name("my-secret-AbCdEf"));
+    let typed: SecretsManagerResource =
+        parse("arn:aws:secretsmanager:us-east-1:123456789012:secret:database");
+    assert!(typed.matches_name("database"));
+    // without the caller saying it is complete, a name is never split at a possible suffix.
+    let typed: SecretsManagerResource =
+        parse("arn:aws:secretsmanager:us-east-1:123456789012:secret:prod-config");
+    assert!(typed.matches_name("prod-config"));
+    assert!(!typed.matches_name("prod"));
+    let typed: SecretsManagerResource =
+        parse("arn:aws:secretsmanager:us-east-1:123456789012:secret:my-secret-??????");
+    assert!(typed.matches_name("my-secret"));
+    assert!(!typed.matches_name("my-secret-??????"));
+}
+
+#[test]
+fn test_from_complete() {
+    let arn =
+        ResourceName::from_str("arn:aws:secretsmanager:us-east-1:123456789012:secret:database")
+            .unwrap();
+    assert_eq!(
+        SecretsManagerResource::from_complete(&arn),
+        Err(Error::InvalidResource("secret:database".to_string()))
+    );
+    let arn = ResourceName::from_str(
+        "arn:aws:secretsmanager:us-east-1:123456789012:secret:my-secret-??????",
+    )
+    .unwrap();
+    assert_eq!(
+        SecretsManagerResource::from_complete(&arn),
+        SecretsManagerResource::try_from(&arn)
+    );
+}
+
+#[test]
+fn test_partial_secret() {
+    let arn = secretsmanager::partial_secret(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        name("my-secret"),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:secretsmanager:us-east-1:123456789012:secret:my-secret"
+    );
+    let typed = SecretsManagerResource::try_from(&arn).unwrap();
+    assert!(typed.matches_name("my-secret"));
+    assert_eq!(typed.to_arn(), arn);
+    assert!(secretsmanager::partial_secret(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        name("my secret"),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_secret_pattern() {
+    let arn = secretsmanager::secret_pattern(
+        Partition::Aws.into(),
+        Region::UsEast1.into(),
+        account(),
+        name("my-secret"),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:secretsmanager:us-east-1:123456789012:secret:my-secret-??????"
+    );
+    let typed = SecretsManagerResource::try_from(&arn).unwrap();
+    assert!(typed.matches_name("my-secret"));
+    assert_eq!(typed.to_arn(), arn);
+}
+
+#[test]
+fn test_known_resource() {
+    let arn = ResourceName::from_str(
+        "arn:aws:secretsmanager:us-east-1:123456789012:secret:my-secret-AbCdEf",
+    )
+    .unwrap();
+    assert!(matches!(
+        KnownResource::try_from(&arn),
+        Ok(KnownResource::SecretsManager(_))
+    ));
+    let arn =
+        ResourceName::from_str("arn:aws:secretsmanager:us-east-1:123456789012:vault:my-secret")
+            .unwrap();
+    assert_eq!(
+        SecretsManagerResource::try_from(&arn),
+        Err(Error::InvalidResource("vault:my-secret".to_string()))
+    );
+}
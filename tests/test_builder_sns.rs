@@ -0,0 +1,194 @@
+use aws_arn::builder::sns::{self, SnsResource};
+use aws_arn::builder::ServiceResource;
+use aws_arn::known::{Partition, Region};
+use aws_arn::{Error, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::{account, id};
+
+const SUBSCRIPTION_ID: &str = "8a21d249-4329-4871-acc6-7be709c6ea7f";
+
+fn topic(name: &str) -> Result<ResourceName, Error> {
+    sns::topic(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        id(name),
+    )
+}
+
+fn subscription(topic_name: &str) -> Result<ResourceName, Error> {
+    sns::subscription(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        id(topic_name),
+        id(SUBSCRIPTION_ID),
+    )
+}
+
+#[test]
+fn test_topic_round_trip() {
+    let arn = topic("my-topic").unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:sns:us-east-2:123456789012:my-topic"
+    );
+    let typed = SnsResource::try_from(&arn).unwrap();
+    assert_eq!(typed.resource_type_name(), "topic");
+    assert!(!typed.is_fifo());
+    assert_eq!(typed.to_arn(), arn);
+}
+
+#[test]
+fn test_fifo_topic_names() {
+    assert!(SnsResource::try_from(&topic("orders.fifo").unwrap())
+        .unwrap()
+        .is_fifo());
+    assert!(topic(&format!("{}.fifo", "t".repeat(251))).is_ok());
+    assert!(topic(&format!("{}.fifo", "t".repeat(252))).is_err());
+    assert_eq!(
+        topic("orders.v2"),
+        Err(Error::InvalidIdentifier("orders.v2".to_string()))
+    );
+    assert!(topic(".fifo").is_err());
+}
+
+#[test]
+fn test_subscription_round_trip() {
+    let arn = subscription("orders.fifo").unwrap();
+    assert_eq!(
+        arn.to_string(),
+        format!(
+            "arn:aws:sns:us-east-2:123456789012:orders.fifo:{}",
+            SUBSCRIPTION_ID
+        )
+    );
+    let typed = SnsResource::try_from(&arn).unwrap();
+    assert_eq!(typed.resource_type_name(), "subscription");
+    assert!(typed.is_fifo());
+    assert_eq!(typed.to_arn(), arn);
+    assert!(sns::subscription(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        id("my-topic"),
+        id("not-a-uuid"),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_topic_from() {
+    let topic_arn = topic("orders.fifo").unwrap();
+    assert_eq!(
+        sns::topic_from(&subscription("orders.fifo").unwrap()),
+        Ok(topic_arn.clone())
+    );
+    assert_eq!(sns::topic_from(&topic_arn), Ok(topic_arn));
+    let parsed = ResourceName::from_str(&format!(
+        "arn:aws:sns:us-east-2:123456789012:my-topic:{}",
+        SUBSCRIPTION_ID
+    ))
+    .unwrap();
+    assert_eq!(
+        sns::topic_from(&parsed).unwrap().to_string(),
+        "arn:aws:sns:us-east-2:123456789012:my-topic"
+    );
+}
+
+#[test]
+fn test_platform_arns() {
+    let app = sns::platform_application(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        id("APNS"),
+        id("MyApp.ios"),
+    )
+    .unwrap();
+    assert_eq!(
+        app.to_string(),
+        "arn:aws:sns:us-east-2:123456789012:app/APNS/MyApp.ios"
+    );
+    let typed = SnsResource::try_from(&app).unwrap();
+    assert_eq!(typed.resource_type_name(), "app");
+    assert_eq!(typed.to_arn(), app);
+
+    let endpoint = sns::platform_endpoint(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        id("GCM"),
+        id("MyApp"),
+        id(SUBSCRIPTION_ID),
+    )
+    .unwrap();
+    assert_eq!(
+        endpoint.to_string(),
+        format!(
+            "arn:aws:sns:us-east-2:123456789012:endpoint/GCM/MyApp/{}",
+            SUBSCRIPTION_ID
+        )
+    );
+    let typed = SnsResource::try_from(&endpoint).unwrap();
+    assert_eq!(typed.resource_type_name(), "endpoint");
+    assert_eq!(typed.to_arn(), endpoint);
+    assert!(matches!(
+        sns::topic_from(&endpoint),
+        Err(Error::ResourceMismatch { .. })
+    ));
+
+    assert_eq!(
+        sns::platform_application(
+            Partition::Aws.into(),
+            Region::UsEast2.into(),
+            account(),
+            id("PIGEON"),
+            id("MyApp"),
+        ),
+        Err(Error::InvalidIdentifier("PIGEON".to_string()))
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let arn = ResourceName::from_str("arn:aws:sns:us-east-2:123456789012:app/APNS").unwrap();
+    assert_eq!(
+        SnsResource::try_from(&arn),
+        Err(Error::InvalidResource("app/APNS".to_string()))
+    );
+    for resource in [
+        "my.topic",
+        "my-topic:not-a-uuid",
+        "app/PIGEON/MyApp",
+        "app/APNS/My App",
+        "endpoint/GCM/MyApp/not-a-uuid",
+    ] {
+        let arn =
+            ResourceName::from_str(&format!("arn:aws:sns:us-east-2:123456789012:{}", resource))
+                .unwrap();
+        assert_eq!(
+            SnsResource::try_from(&arn),
+            Err(Error::InvalidResource(resource.to_string()))
+        );
+    }
+    for resource in [
+        "my-topic-*",
+        "my-topic:*",
+        "app/*/MyApp",
+        "endpoint/GCM/*/*",
+    ] {
+        let arn =
+            ResourceName::from_str(&format!("arn:aws:sns:us-east-2:123456789012:{}", resource))
+                .unwrap();
+        assert!(SnsResource::try_from(&arn).is_ok());
+    }
+    let arn = ResourceName::from_str("arn:aws:sqs:us-east-2:123456789012:my-queue").unwrap();
+    assert!(matches!(
+        SnsResource::try_from(&arn),
+        Err(Error::ResourceMismatch { .. })
+    ));
+}
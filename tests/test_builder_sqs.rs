@@ -0,0 +1,141 @@
+use aws_arn::builder::sqs::{self, SqsResource};
+use aws_arn::builder::ServiceResource;
+use aws_arn::known::{Partition, Region};
+use aws_arn::{Error, Identifier, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::account;
+
+fn queue(name: &str) -> Result<ResourceName, Error> {
+    sqs::queue(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        Identifier::from_str(name).unwrap(),
+    )
+}
+
+#[test]
+fn test_queue_round_trip() {
+    let arn = queue("my-queue").unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:sqs:us-east-2:123456789012:my-queue"
+    );
+    let typed = SqsResource::try_from(&arn).unwrap();
+    assert_eq!(typed.resource_type_name(), "queue");
+    assert!(!typed.is_fifo());
+    assert_eq!(typed.to_arn(), arn);
+}
+
+#[test]
+fn test_fifo_queue_names() {
+    let arn = queue("orders.fifo").unwrap();
+    assert!(SqsResource::try_from(&arn).unwrap().is_fifo());
+    assert!(queue(&format!("{}.fifo", "q".repeat(75))).is_ok());
+    assert!(queue(&format!("{}.fifo", "q".repeat(76))).is_err());
+    assert!(queue(&"q".repeat(80)).is_ok());
+    assert!(queue(&"q".repeat(81)).is_err());
+    assert_eq!(
+        queue("orders.fif"),
+        Err(Error::InvalidIdentifier("orders.fif".to_string()))
+    );
+    assert!(queue(".fifo").is_err());
+    assert!(queue("my-queue-*").is_ok());
+}
+
+#[test]
+fn test_from_url() {
+    let arn = sqs::from_url("https://sqs.us-east-2.amazonaws.com/123456789012/my-queue").unwrap();
+    assert_eq!(arn, queue("my-queue").unwrap());
+    assert_eq!(
+        sqs::from_url("https://queue.amazonaws.com/123456789012/my-queue")
+            .unwrap()
+            .to_string(),
+        "arn:aws:sqs:us-east-1:123456789012:my-queue"
+    );
+    assert_eq!(
+        sqs::from_url("https://eu-west-1.queue.amazonaws.com/123456789012/orders.fifo")
+            .unwrap()
+            .to_string(),
+        "arn:aws:sqs:eu-west-1:123456789012:orders.fifo"
+    );
+    assert_eq!(
+        sqs::from_url("https://sqs.cn-north-1.amazonaws.com.cn/123456789012/my-queue")
+            .unwrap()
+            .to_string(),
+        "arn:aws-cn:sqs:cn-north-1:123456789012:my-queue"
+    );
+    assert_eq!(
+        sqs::from_url("https://sqs.us-gov-west-1.amazonaws.com/123456789012/my-queue")
+            .unwrap()
+            .to_string(),
+        "arn:aws-us-gov:sqs:us-gov-west-1:123456789012:my-queue"
+    );
+}
+
+#[test]
+fn test_from_url_invalid() {
+    for url in [
+        "sqs.us-east-2.amazonaws.com/123456789012/my-queue",
+        "https://sqs.us-east-2.example.com/123456789012/my-queue",
+        "https://sqs.us-east-2.amazonaws.com/123456789012",
+        "https://sqs.us-east-2.amazonaws.com/123456789012/my-queue/extra",
+        "https://sqs.us-east-2.amazonaws.com/my-account/my-queue",
+        "https://sqs.us-east-2.amazonaws.com/123456789012/my.queue",
+        "https://sqs.queue.amazonaws.com/123456789012/my-queue",
+        "https://queue.sqs.amazonaws.com/123456789012/my-queue",
+    ] {
+        assert_eq!(sqs::from_url(url), Err(Error::InvalidUrl(url.to_string())));
+    }
+}
+
+#[test]
+fn test_to_url() {
+    let arn = queue("orders.fifo").unwrap();
+    assert_eq!(
+        sqs::to_url(&arn).unwrap(),
+        "https://sqs.us-east-2.amazonaws.com/123456789012/orders.fifo"
+    );
+    let china = ResourceName::from_str("arn:aws-cn:sqs:cn-north-1:123456789012:my-queue").unwrap();
+    let url = sqs::to_url(&china).unwrap();
+    assert_eq!(
+        url,
+        "https://sqs.cn-north-1.amazonaws.com.cn/123456789012/my-queue"
+    );
+    assert_eq!(sqs::from_url(&url), Ok(china));
+    let function =
+        ResourceName::from_str("arn:aws:lambda:us-east-2:123456789012:function:my-function")
+            .unwrap();
+    assert!(matches!(
+        sqs::to_url(&function),
+        Err(Error::ResourceMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_to_url_invalid() {
+    for (s, error) in [
+        (
+            "arn:aws:sqs:*:123456789012:my-queue",
+            Error::RegionWildcardNotAllowed,
+        ),
+        (
+            "arn:aws:sqs:us-east-2:*:my-queue",
+            Error::AccountIdWildcardNotAllowed,
+        ),
+        (
+            "arn:aws:sqs:us-east-2:123456789012:my-queue-*",
+            Error::ResourceWildcardNotAllowed,
+        ),
+        (
+            "arn:aws:sqs:us-east-2:123456789012:my.queue",
+            Error::InvalidIdentifier("my.queue".to_string()),
+        ),
+    ] {
+        let arn = ResourceName::from_str(s).unwrap();
+        assert_eq!(sqs::to_url(&arn), Err(error));
+    }
+}
@@ -0,0 +1,199 @@
+use aws_arn::builder::ssm::{self, SsmResource};
+use aws_arn::builder::ServiceResource;
+use aws_arn::known::{Partition, Region};
+use aws_arn::{AccountIdentifier, Error, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::{account, id, resource_id};
+
+fn parameter(account: Option<AccountIdentifier>, name: &str) -> Result<ResourceName, Error> {
+    ssm::parameter(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account,
+        resource_id(name),
+    )
+}
+
+#[test]
+fn test_hierarchical_parameter() {
+    let arn = parameter(Some(account()), "/app/prod/db/password").unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ssm:us-east-2:123456789012:parameter/app/prod/db/password"
+    );
+    assert_eq!(
+        parameter(Some(account()), "app/prod/db/password"),
+        Ok(arn.clone())
+    );
+    let typed = SsmResource::try_from(&arn).unwrap();
+    assert_eq!(typed.resource_type_name(), "parameter");
+    assert_eq!(
+        typed.parameter_name(),
+        Some("/app/prod/db/password".to_string())
+    );
+    assert_eq!(typed.to_arn(), arn);
+}
+
+#[test]
+fn test_simple_parameter() {
+    let arn = parameter(Some(account()), "my-param").unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ssm:us-east-2:123456789012:parameter/my-param"
+    );
+    assert_eq!(
+        SsmResource::try_from(&arn).unwrap().parameter_name(),
+        Some("my-param".to_string())
+    );
+}
+
+#[test]
+fn test_public_parameter() {
+    let name = "/aws/service/ami-amazon-linux-latest/al2023-ami-kernel-default-x86_64";
+    let arn = parameter(None, name).unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ssm:us-east-2::parameter/aws/service/ami-amazon-linux-latest/al2023-ami-kernel-default-x86_64"
+    );
+    let parsed = ResourceName::from_str(&arn.to_string()).unwrap();
+    let typed = SsmResource::try_from(&parsed).unwrap();
+    assert_eq!(typed.parameter_name(), Some(name.to_string()));
+    assert_eq!(typed.to_arn(), arn);
+    assert_eq!(
+        parameter(None, "/app/prod/db/password"),
+        Err(Error::MissingAccountId)
+    );
+}
+
+#[test]
+fn test_invalid_parameters() {
+    for name in [
+        "/aws/service/my-param",
+        "/SSM-params/x",
+        "/app//db",
+        "/app/db/",
+        "/app/db password",
+        "/1/2/3/4/5/6/7/8/9/10/11/12/13/14/15/16",
+    ] {
+        assert!(parameter(Some(account()), name).is_err(), "{}", name);
+    }
+    assert!(parameter(Some(account()), "/app/prod/*").is_ok());
+}
+
+#[test]
+fn test_documents() {
+    let aws_owned = ssm::document(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        None,
+        id("AWS-RunShellScript"),
+    )
+    .unwrap();
+    assert_eq!(
+        aws_owned.to_string(),
+        "arn:aws:ssm:us-east-2::document/AWS-RunShellScript"
+    );
+    let typed = SsmResource::try_from(&aws_owned).unwrap();
+    assert_eq!(typed.resource_type_name(), "document");
+    assert_eq!(typed.parameter_name(), None);
+    assert_eq!(typed.to_arn(), aws_owned);
+    assert!(ssm::document(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        Some(account()),
+        id("ab"),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_automation_definitions() {
+    let arn = ssm::automation_definition(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        id("MyRunbook"),
+        Some(id("$DEFAULT")),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ssm:us-east-2:123456789012:automation-definition/MyRunbook:$DEFAULT"
+    );
+    let typed = SsmResource::try_from(&arn).unwrap();
+    assert_eq!(
+        typed,
+        SsmResource::AutomationDefinition {
+            partition: Partition::Aws.into(),
+            region: Region::UsEast2.into(),
+            account: account(),
+            name: id("MyRunbook"),
+            version: Some(id("$DEFAULT")),
+        }
+    );
+    assert_eq!(typed.to_arn(), arn);
+    assert!(ssm::automation_definition(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        id("MyRunbook"),
+        Some(id("$NEWEST")),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_windows_and_instances() {
+    let window = ssm::maintenance_window(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        id("mw-0c50858d01e8a2b3c"),
+    )
+    .unwrap();
+    assert_eq!(
+        window.to_string(),
+        "arn:aws:ssm:us-east-2:123456789012:maintenancewindow/mw-0c50858d01e8a2b3c"
+    );
+    assert_eq!(SsmResource::try_from(&window).unwrap().to_arn(), window);
+    let instance = ssm::managed_instance(
+        Partition::Aws.into(),
+        Region::UsEast2.into(),
+        account(),
+        id("mi-1234567890abcdef0"),
+    )
+    .unwrap();
+    assert_eq!(
+        instance.to_string(),
+        "arn:aws:ssm:us-east-2:123456789012:managed-instance/mi-1234567890abcdef0"
+    );
+    let typed = SsmResource::try_from(&instance).unwrap();
+    assert_eq!(typed.resource_type_name(), "managed-instance");
+    assert_eq!(typed.to_arn(), instance);
+    assert_eq!(
+        ssm::managed_instance(
+            Partition::Aws.into(),
+            Region::UsEast2.into(),
+            account(),
+            id("i-1234567890abcdef0"),
+        ),
+        Err(Error::InvalidIdentifier("i-1234567890abcdef0".to_string()))
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let arn =
+        ResourceName::from_str("arn:aws:ssm:us-east-2::maintenancewindow/mw-0c50858d01e8a2b3c")
+            .unwrap();
+    assert_eq!(SsmResource::try_from(&arn), Err(Error::MissingAccountId));
+    let arn =
+        ResourceName::from_str("arn:aws:ssm:us-east-2:123456789012:parameter//app/db").unwrap();
+    assert_eq!(
+        SsmResource::try_from(&arn),
+        Err(Error::InvalidResource("parameter//app/db".to_string()))
+    );
+}
@@ -0,0 +1,124 @@
+use aws_arn::builder::sts::{self, RolePathResolver, StsResource};
+use aws_arn::known::Partition;
+use aws_arn::{AccountIdentifier, Error, Identifier, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod common;
+use common::account;
+
+#[test]
+fn test_assumed_role_round_trip() {
+    let arn = sts::assumed_role(
+        Partition::Aws.into(),
+        account(),
+        Identifier::from_str("S3Access").unwrap(),
+        Identifier::from_str("jane@example.com").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:sts::123456789012:assumed-role/S3Access/jane@example.com"
+    );
+    let typed = StsResource::try_from(&arn).unwrap();
+    assert_eq!(
+        typed,
+        StsResource::AssumedRole {
+            partition: Partition::Aws.into(),
+            account: account(),
+            role_name: Identifier::from_str("S3Access").unwrap(),
+            session_name: Identifier::from_str("jane@example.com").unwrap(),
+        }
+    );
+    assert_eq!(ResourceName::from(typed), arn);
+}
+
+#[test]
+fn test_federated_user_round_trip() {
+    let arn = ResourceName::from_str("arn:aws:sts::123456789012:federated-user/Bob").unwrap();
+    let typed = StsResource::try_from(&arn).unwrap();
+    assert!(matches!(typed, StsResource::FederatedUser { .. }));
+    assert_eq!(ResourceName::from(typed), arn);
+}
+
+#[test]
+fn test_role_from_assumed_role() {
+    let arn = ResourceName::from_str(
+        "arn:aws:sts::123456789012:assumed-role/my-role/i-0123456789abcdef0",
+    )
+    .unwrap();
+
+    let root_path = |_: &AccountIdentifier, _: &Identifier| Ok(vec![]);
+    assert_eq!(
+        sts::role_from(&arn, &root_path).unwrap().to_string(),
+        "arn:aws:iam::123456789012:role/my-role"
+    );
+
+    let team_path = |_: &AccountIdentifier, role_name: &Identifier| {
+        assert_eq!(role_name.to_string(), "my-role");
+        Ok(vec![
+            Identifier::from_str("service-role").unwrap(),
+            Identifier::from_str("team").unwrap(),
+        ])
+    };
+    assert_eq!(
+        sts::role_from(&arn, &team_path).unwrap().to_string(),
+        "arn:aws:iam::123456789012:role/service-role/team/my-role"
+    );
+
+    let unknown = |_: &AccountIdentifier, role_name: &Identifier| {
+        Err(Error::InvalidResource(role_name.to_string()))
+    };
+    assert_eq!(
+        sts::role_from(&arn, &unknown),
+        Err(Error::InvalidResource("my-role".to_string()))
+    );
+}
+
+#[test]
+fn test_custom_resolver() {
+    struct Lookup;
+
+    impl RolePathResolver for Lookup {
+        fn resolve_role_path(
+            &self,
+            _: &AccountIdentifier,
+            _: &Identifier,
+        ) -> Result<Vec<Identifier>, Error> {
+            Ok(vec![Identifier::from_str("admin").unwrap()])
+        }
+    }
+
+    let arn =
+        ResourceName::from_str("arn:aws:sts::123456789012:assumed-role/Admin/session").unwrap();
+    assert_eq!(
+        sts::role_from(&arn, &Lookup).unwrap().to_string(),
+        "arn:aws:iam::123456789012:role/admin/Admin"
+    );
+
+    let arn = ResourceName::from_str("arn:aws:sts::123456789012:federated-user/Bob").unwrap();
+    assert_eq!(
+        sts::role_from(&arn, &Lookup),
+        Err(Error::ResourceMismatch {
+            expected: "assumed-role".to_string(),
+            found: "federated-user".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_invalid_names() {
+    assert_eq!(
+        sts::federated_user(
+            Partition::Aws.into(),
+            account(),
+            Identifier::from_str("B").unwrap(),
+        ),
+        Err(Error::InvalidIdentifier("B".to_string()))
+    );
+    let arn = ResourceName::from_str("arn:aws:sts::123456789012:assumed-role/my-role").unwrap();
+    assert_eq!(
+        StsResource::try_from(&arn),
+        Err(Error::InvalidResource("assumed-role/my-role".to_string()))
+    );
+}
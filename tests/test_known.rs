@@ -0,0 +1,127 @@
+use aws_arn::known::{ManagedPolicy, Partition, Presence, Region, Service, ServiceLinkedRole};
+use aws_arn::{AccountIdentifier, Error, ResourceName};
+use std::str::FromStr;
+
+#[test]
+fn test_partition_round_trip() {
+    assert_eq!(Partition::AwsUsGov.to_string(), "aws-us-gov");
+    assert_eq!(Partition::from_str("aws-cn"), Ok(Partition::AwsChina));
+    assert_eq!(Partition::from_str("azure"), Err(Error::InvalidPartition));
+}
+
+#[test]
+fn test_region_round_trip() {
+    assert_eq!(Region::EuWest1.to_string(), "eu-west-1");
+    assert_eq!(Region::from_str("us-east-2"), Ok(Region::UsEast2));
+    assert_eq!(Region::from_str("us-east-9"), Err(Error::InvalidRegion));
+}
+
+#[test]
+fn test_service_round_trip() {
+    assert_eq!(Service::CloudWatchLogs.to_string(), "logs");
+    assert_eq!(Service::from_str("lambda"), Ok(Service::Lambda));
+    assert_eq!(Service::from_str("lambada"), Err(Error::InvalidService));
+    for service in [
+        Service::AccessAnalyzer,
+        Service::EventBridgePipes,
+        Service::EventBridgeScheduler,
+        Service::XRay,
+    ] {
+        assert_eq!(Service::from_str(&service.to_string()), Ok(service));
+    }
+}
+
+#[test]
+fn test_resource_presence() {
+    assert_eq!(Service::DynamoDb.region_presence(), Presence::Optional);
+    assert_eq!(
+        Service::DynamoDb.region_presence_for("global-table/Books"),
+        Presence::NotAllowed
+    );
+    assert_eq!(
+        Service::DynamoDb.region_presence_for("table/Books"),
+        Presence::Required
+    );
+    assert_eq!(
+        Service::S3.region_presence_for("my-bucket"),
+        Presence::NotAllowed
+    );
+    assert_eq!(
+        Service::S3.account_presence_for("my-bucket"),
+        Presence::NotAllowed
+    );
+    assert_eq!(
+        Service::S3.region_presence_for("job/a1b2c3"),
+        Presence::Optional
+    );
+    assert_eq!(
+        Service::S3.account_presence_for("job/a1b2c3"),
+        Presence::Required
+    );
+    assert_eq!(
+        Service::Lambda.region_presence_for("function:hello"),
+        Service::Lambda.region_presence()
+    );
+}
+
+#[test]
+fn test_managed_policy_arns() {
+    let policy = ManagedPolicy::AWSLambdaBasicExecutionRole;
+    assert_eq!(policy.name(), "AWSLambdaBasicExecutionRole");
+    assert_eq!(policy.path(), "service-role");
+    assert_eq!(
+        policy.to_arn(Partition::Aws).to_string(),
+        "arn:aws:iam::aws:policy/service-role/AWSLambdaBasicExecutionRole"
+    );
+    assert_eq!(
+        policy.to_arn(Partition::AwsUsGov).to_string(),
+        "arn:aws-us-gov:iam::aws:policy/service-role/AWSLambdaBasicExecutionRole"
+    );
+    assert_eq!(
+        ManagedPolicy::ReadOnlyAccess
+            .to_arn(Partition::AwsChina)
+            .to_string(),
+        "arn:aws-cn:iam::aws:policy/ReadOnlyAccess"
+    );
+    assert_eq!(
+        ManagedPolicy::AWSLambda_FullAccess.to_string(),
+        "AWSLambda_FullAccess"
+    );
+    assert_eq!(
+        ManagedPolicy::AWSLambdaInvocation_DynamoDB.to_string(),
+        "AWSLambdaInvocation-DynamoDB"
+    );
+}
+
+#[test]
+fn test_managed_policy_round_trip() {
+    for policy in ManagedPolicy::ALL {
+        assert_eq!(ManagedPolicy::from_str(policy.name()), Ok(*policy));
+        let arn = policy.to_arn(Partition::Aws);
+        assert_eq!(ResourceName::from_str(&arn.to_string()), Ok(arn));
+    }
+    assert_eq!(
+        ManagedPolicy::from_str("AdminAccess"),
+        Err(Error::InvalidResource("AdminAccess".to_string()))
+    );
+}
+
+#[test]
+fn test_service_linked_role_arns() {
+    let account = AccountIdentifier::from_str("123456789012").unwrap();
+    let role = ServiceLinkedRole::AWSServiceRoleForElasticBeanstalk;
+    assert_eq!(role.service_principal(), "elasticbeanstalk.amazonaws.com");
+    assert_eq!(
+        role.to_arn(Partition::AwsUsGov, account).to_string(),
+        "arn:aws-us-gov:iam::123456789012:role/aws-service-role/elasticbeanstalk.amazonaws.com/AWSServiceRoleForElasticBeanstalk"
+    );
+    for role in ServiceLinkedRole::ALL {
+        assert_eq!(ServiceLinkedRole::from_str(role.name()), Ok(*role));
+    }
+    assert!(ServiceLinkedRole::from_str("AWSServiceRoleForNothing").is_err());
+    assert!(ServiceLinkedRole::from_str("AWSServiceRoleForLambda").is_err());
+    assert_eq!(
+        ServiceLinkedRole::AWSServiceRoleForAPIGateway.service_principal(),
+        "ops.apigateway.amazonaws.com"
+    );
+}
@@ -0,0 +1,68 @@
+use aws_arn::builder::lambda::LambdaResource;
+use aws_arn::builder::{KnownResource, ServiceResource};
+use aws_arn::{Error, ResourceName};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+fn known(s: &str) -> Result<KnownResource, Error> {
+    KnownResource::try_from(&ResourceName::from_str(s).unwrap())
+}
+
+#[test]
+fn test_dispatch_to_service() {
+    let resource = known("arn:aws:lambda:us-east-2:123456789012:layer:my-layer:3").unwrap();
+    assert!(matches!(
+        resource,
+        KnownResource::Lambda(LambdaResource::LayerVersion { version: 3, .. })
+    ));
+    assert_eq!(resource.service().to_string(), "lambda");
+    assert_eq!(resource.resource_type_name(), "layer");
+}
+
+#[test]
+fn test_unknown_fallback() {
+    let resource = known("arn:aws:cloudwatch:us-west-2:123456789012:alarm:Production").unwrap();
+    assert!(matches!(resource, KnownResource::Unknown(_)));
+    assert_eq!(resource.service().to_string(), "cloudwatch");
+    assert_eq!(resource.resource_type_name(), "alarm");
+
+    let resource = known("arn:aws:iam::123456789012:unknown-type/Webserver").unwrap();
+    assert!(matches!(resource, KnownResource::Unknown(_)));
+}
+
+#[test]
+fn test_unparsed_known_service() {
+    for s in [
+        "arn:aws:lambda:us-east-2::function:my-function",
+        "arn:aws:lambda:us-east-2:123456789012:code-signing-config:csc-0123456789abcdef0",
+        "arn:aws:sqs::123456789012:my-queue",
+        "arn:aws:dynamodb:us-west-2:123456789012:global-table/Books",
+        "arn:aws:sqs:us-east-2:123456789012:my-queue:extra",
+    ] {
+        let arn = ResourceName::from_str(s).unwrap();
+        assert_eq!(
+            KnownResource::try_from(&arn),
+            Ok(KnownResource::Unknown(arn))
+        );
+    }
+}
+
+#[test]
+fn test_heterogeneous_list() {
+    let arns = [
+        "arn:aws:s3:::my-bucket",
+        "arn:aws:s3:::my-bucket/photos/cat.jpg",
+        "arn:aws:iam::123456789012:role/S3Access",
+        "arn:aws:lambda:us-east-2:123456789012:function:my-function",
+        "arn:aws:sqs:us-east-2:123456789012:my-queue",
+    ];
+    let resources: Vec<Box<dyn ServiceResource>> = arns
+        .iter()
+        .map(|s| Box::new(known(s).unwrap()) as Box<dyn ServiceResource>)
+        .collect();
+    let types: Vec<&str> = resources.iter().map(|r| r.resource_type_name()).collect();
+    assert_eq!(types, vec!["bucket", "object", "role", "function", "queue"]);
+    for (resource, arn) in resources.iter().zip(arns.iter()) {
+        assert_eq!(resource.to_arn().to_string(), *arn);
+    }
+}
@@ -0,0 +1,49 @@
+use aws_arn::builder::typed::{ArnBuilder, Iam, Lambda, S3Regional, S3};
+use aws_arn::known::Partition::AwsChina;
+use aws_arn::known::Region::UsEast2;
+use aws_arn::{AccountIdentifier, ResourceIdentifier, ResourceName};
+use std::str::FromStr;
+
+#[test]
+fn test_s3_bucket() {
+    let arn: ResourceName = ArnBuilder::service(S3)
+        .resource(ResourceIdentifier::from_str("my-bucket").unwrap())
+        .build();
+    assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket");
+}
+
+#[test]
+fn test_s3_job() {
+    let arn: ResourceName = ArnBuilder::service(S3Regional)
+        .in_region(UsEast2)
+        .owned_by(AccountIdentifier::from_str("123456789012").unwrap())
+        .is(ResourceIdentifier::from_str("job/23476").unwrap())
+        .into();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3:us-east-2:123456789012:job/23476"
+    );
+}
+
+#[test]
+fn test_iam_user_in_partition() {
+    let arn: ResourceName = ArnBuilder::service(Iam)
+        .in_partition(AwsChina)
+        .in_account(AccountIdentifier::from_str("123456789012").unwrap())
+        .resource(ResourceIdentifier::from_str("user/JohnDoe").unwrap())
+        .build();
+    assert_eq!(arn.to_string(), "arn:aws-cn:iam::123456789012:user/JohnDoe");
+}
+
+#[test]
+fn test_lambda_layer() {
+    let arn: ResourceName = ArnBuilder::service(Lambda)
+        .resource(ResourceIdentifier::from_str("layer:my-layer:3").unwrap())
+        .in_region(UsEast2)
+        .owned_by(AccountIdentifier::from_str("123456789012").unwrap())
+        .build();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:lambda:us-east-2:123456789012:layer:my-layer:3"
+    );
+}